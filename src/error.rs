@@ -16,6 +16,27 @@ pub enum Error {
     /// An error caused by a network error.
     #[error("http error: {0}")]
     Request(#[from] reqwest::Error),
+    /// Callback data for an inline keyboard button was not 1-64 bytes.
+    #[error("callback data must be 1-64 bytes, got {0}")]
+    CallbackDataLength(usize),
+    /// An inline keyboard button did not have exactly one action.
+    #[error("inline keyboard button must have exactly one action, got {0}")]
+    InlineKeyboardButtonActions(usize),
+    /// An error decrypting Telegram Passport data.
+    #[cfg(feature = "passport")]
+    #[error("passport error: {0}")]
+    Passport(#[from] crate::PassportError),
+}
+
+impl Error {
+    /// The kind of error returned by Telegram, if this error originated from
+    /// Telegram.
+    pub fn kind(&self) -> Option<TelegramErrorKind> {
+        match self {
+            Error::Telegram(err) => Some(err.kind()),
+            _ => None,
+        }
+    }
 }
 
 /// Additional information returned by Telegram with an error.
@@ -39,6 +60,54 @@ pub struct TelegramError {
     pub parameters: Option<ResponseParameters>,
 }
 
+impl TelegramError {
+    /// Classify this error based on the error code, description, and
+    /// parameters returned by Telegram.
+    pub fn kind(&self) -> TelegramErrorKind {
+        let parameters = self.parameters.as_ref();
+
+        if let Some(to) = parameters.and_then(|parameters| parameters.migrate_to_chat_id) {
+            return TelegramErrorKind::Migrated { to };
+        }
+
+        let retry_after = parameters.and_then(|parameters| parameters.retry_after);
+        let description = self.description.clone().unwrap_or_default();
+        let lowercase = description.to_lowercase();
+
+        match self.error_code {
+            Some(429) => TelegramErrorKind::TooManyRequests { retry_after },
+            _ if retry_after.is_some() => TelegramErrorKind::TooManyRequests { retry_after },
+            Some(401) => TelegramErrorKind::Unauthorized,
+            Some(403) if lowercase.contains("bot was blocked by the user") => {
+                TelegramErrorKind::Blocked
+            }
+            Some(403) if lowercase.contains("user is deactivated") => {
+                TelegramErrorKind::UserDeactivated
+            }
+            Some(403) if lowercase.contains("bot was kicked") => TelegramErrorKind::Kicked,
+            Some(403) => TelegramErrorKind::Forbidden(description),
+            Some(400) if lowercase.contains("chat not found") => TelegramErrorKind::ChatNotFound,
+            Some(400) if lowercase.contains("message is not modified") => {
+                TelegramErrorKind::MessageNotModified
+            }
+            Some(400)
+                if lowercase.contains("message to edit not found")
+                    || lowercase.contains("message to delete not found")
+                    || lowercase.contains("message not found") =>
+            {
+                TelegramErrorKind::MessageNotFound
+            }
+            Some(400) => TelegramErrorKind::BadRequest(description),
+            Some(404) => TelegramErrorKind::NotFound,
+            Some(409) => TelegramErrorKind::Conflict(description),
+            error_code => TelegramErrorKind::Other {
+                error_code,
+                description,
+            },
+        }
+    }
+}
+
 impl std::error::Error for TelegramError {}
 
 impl std::fmt::Display for TelegramError {
@@ -53,3 +122,43 @@ impl std::fmt::Display for TelegramError {
         )
     }
 }
+
+/// A classification of a [TelegramError].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TelegramErrorKind {
+    /// The bot token is invalid or has been revoked.
+    Unauthorized,
+    /// The user has blocked the bot.
+    Blocked,
+    /// The user's account has been deleted.
+    UserDeactivated,
+    /// The bot was removed from the group or channel.
+    Kicked,
+    /// The bot is not allowed to perform the request for another reason,
+    /// with the description from Telegram.
+    Forbidden(String),
+    /// The chat does not exist or the bot has no access to it.
+    ChatNotFound,
+    /// An edit did not change the message's content or markup.
+    MessageNotModified,
+    /// The message to edit or delete could not be found.
+    MessageNotFound,
+    /// Too many requests were made, the request may be retried after the
+    /// given number of seconds.
+    TooManyRequests { retry_after: Option<i32> },
+    /// The group was migrated to a supergroup with a new ID.
+    Migrated { to: i64 },
+    /// The request was invalid, with the description from Telegram.
+    BadRequest(String),
+    /// The method does not exist.
+    NotFound,
+    /// The request conflicted with another, such as when getting updates
+    /// from multiple instances or while a webhook is set.
+    Conflict(String),
+    /// Any other error.
+    Other {
+        error_code: Option<i32>,
+        description: String,
+    },
+}
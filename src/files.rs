@@ -101,3 +101,32 @@ where
 
     seq.end()
 }
+
+/// Convert stickers that are getting uploaded into attachments, the same as
+/// [clean_input_media].
+///
+/// This will panic if you attempt to upload a Missing file.
+pub fn clean_input_stickers<S>(input_stickers: &[InputSticker], s: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::SerializeSeq;
+
+    let mut seq = s.serialize_seq(Some(input_stickers.len()))?;
+
+    for elem in input_stickers {
+        seq.serialize_element(&elem.attached())?;
+    }
+
+    seq.end()
+}
+
+/// Convert a single sticker into an attachment if it is getting uploaded.
+///
+/// This will panic if you attempt to upload a Missing file.
+pub fn clean_input_sticker<S>(input_sticker: &InputSticker, s: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    input_sticker.attached().serialize(s)
+}
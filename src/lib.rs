@@ -1,12 +1,25 @@
 pub use error::*;
 pub use files::*;
+#[cfg(feature = "passport")]
+pub use passport::*;
+pub use ratelimit::*;
+pub use retry::*;
 pub use types::*;
+#[cfg(feature = "webhook")]
+pub use webhook::*;
 
-use tracing::{debug, error, trace};
+use tracing::{debug, error, trace, warn};
 
 mod error;
 mod files;
+#[cfg(feature = "passport")]
+mod passport;
+mod ratelimit;
+mod retry;
 mod types;
+mod updates;
+#[cfg(feature = "webhook")]
+mod webhook;
 
 /// All of the requests to Telegram.
 pub mod requests;
@@ -19,6 +32,10 @@ static API_ENDPOINT: &str = "https://api.telegram.org/";
 /// Type used for files in [TelegramRequest].
 type RequestFiles = Option<Vec<(String, reqwest::multipart::Part)>>;
 
+/// Callback for when a group migrated to a supergroup, with the old and new
+/// chat IDs.
+type MigrationHandler = Box<dyn Fn(i64, i64) + Send + Sync>;
+
 /// A trait for all Telegram requests.
 ///
 /// It has as many default methods as possible but still requires some additions.
@@ -51,6 +68,10 @@ pub struct Telegram {
     client: reqwest::Client,
 
     api_endpoint: String,
+
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    migration_handler: Option<MigrationHandler>,
 }
 
 impl Telegram {
@@ -70,9 +91,45 @@ impl Telegram {
             api_key,
             client,
             api_endpoint,
+            retry_policy: None,
+            rate_limiter: None,
+            migration_handler: None,
         }
     }
 
+    /// Retry failed requests according to a [RetryPolicy].
+    ///
+    /// By default, requests are never retried.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Throttle requests that send messages using a [RateLimiter].
+    ///
+    /// By default, requests are sent as soon as they are made.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Follow groups that migrated to supergroups.
+    ///
+    /// When Telegram reports that the chat of a request has migrated, the
+    /// handler is called with the old and new chat IDs and the request is sent
+    /// again to the new chat. By default, the error is returned instead.
+    ///
+    /// Only the `chat_id` of a request is followed. Other chat fields, such as
+    /// the `from_chat_id` of a forwarded or copied message, are not updated.
+    /// The re-sent request does not count as an attempt for the retry policy.
+    pub fn with_migration_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(i64, i64) + Send + Sync + 'static,
+    {
+        self.migration_handler = Some(Box::new(handler));
+        self
+    }
+
     /// Make a request for a [TelegramRequest] item and parse the response
     /// into the requested output type if the request succeeded.
     #[tracing::instrument(skip(self, request), fields(method = request.endpoint()))]
@@ -81,13 +138,90 @@ impl Telegram {
         T: TelegramRequest,
     {
         let endpoint = request.endpoint();
+        let mut values = request.values()?;
+
+        let mut attempt = 0;
+        let mut migrated = false;
+
+        loop {
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.wait(endpoint, &values).await;
+            }
+
+            let err = match self.send_request(endpoint, &values, request.files()).await {
+                Ok(resp) => return Ok(resp),
+                Err(err) => err,
+            };
 
+            if !migrated {
+                if let Some((from, to)) = self.migrated_chat(&err, &values) {
+                    debug!(from, to, "Chat migrated, sending request to new chat");
+
+                    if let Some(handler) = &self.migration_handler {
+                        handler(from, to);
+                    }
+
+                    values["chat_id"] = to.into();
+                    migrated = true;
+                    continue;
+                }
+            }
+
+            // Re-sending to a migrated chat does not count as an attempt.
+            attempt += 1;
+
+            let delay = match &self.retry_policy {
+                Some(retry_policy) => retry_policy.delay(attempt, &err),
+                None => None,
+            };
+
+            match delay {
+                Some(delay) => {
+                    warn!(
+                        attempt,
+                        "Request failed, retrying in {:?}: {:?}", delay, err
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                None => return Err(err),
+            }
+        }
+    }
+
+    /// Get the old and new chat IDs if a migration should be followed for a
+    /// request that failed with the given error.
+    fn migrated_chat(&self, err: &Error, values: &serde_json::Value) -> Option<(i64, i64)> {
+        self.migration_handler.as_ref()?;
+
+        let to = match err.kind()? {
+            TelegramErrorKind::Migrated { to } => to,
+            _ => return None,
+        };
+
+        match values
+            .get("chat_id")
+            .and_then(requests::ChatID::from_value)?
+        {
+            requests::ChatID::Identifier(from) => Some((from, to)),
+            requests::ChatID::Username(_) => None,
+        }
+    }
+
+    /// Send a single request to an endpoint with the given values and files.
+    async fn send_request<R>(
+        &self,
+        endpoint: &str,
+        values: &serde_json::Value,
+        files: RequestFiles,
+    ) -> Result<R, Error>
+    where
+        R: serde::de::DeserializeOwned + std::fmt::Debug,
+    {
         let url = format!("{}bot{}/{}", self.api_endpoint, self.api_key, endpoint);
-        let values = request.values()?;
 
         debug!("Making request with values: {:?}", values);
 
-        let resp: types::Response<T::Response> = if let Some(files) = request.files() {
+        let resp: types::Response<R> = if let Some(files) = files {
             // If our request has a file that needs to be uploaded, use
             // a multipart upload. Works by converting each JSON value into
             // a string and putting it into a field with the same name as the
@@ -133,7 +267,7 @@ impl Telegram {
 
             self.client
                 .post(&url)
-                .json(&values)
+                .json(values)
                 .send()
                 .await?
                 .json()
@@ -0,0 +1,307 @@
+use aes::cipher::{block_padding::NoPadding, BlockDecryptMut, KeyIvInit};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rsa::{pkcs1::DecodeRsaPrivateKey, pkcs8::DecodePrivateKey, Oaep, RsaPrivateKey};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha512};
+use thiserror::Error;
+
+use crate::{EncryptedCredentials, EncryptedPassportElement, EncryptedPassportElementType, Error};
+
+type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
+
+/// An error that occurred while decrypting Telegram Passport data.
+#[derive(Error, Debug)]
+pub enum PassportError {
+    /// The private key could not be parsed.
+    #[error("invalid private key: {0}")]
+    Key(String),
+    /// The credentials secret could not be decrypted with the private key.
+    #[error("rsa error: {0}")]
+    Rsa(#[from] rsa::Error),
+    /// A value was not valid base64.
+    #[error("base64 error: {0}")]
+    Base64(#[from] base64::DecodeError),
+    /// The encrypted data was not a multiple of the AES block size.
+    #[error("encrypted data has invalid length")]
+    InvalidLength,
+    /// The decrypted data did not match its hash, most likely because the
+    /// wrong secret was used.
+    #[error("decrypted data does not match hash")]
+    HashMismatch,
+    /// The decrypted data did not start with valid padding.
+    #[error("decrypted data has invalid padding")]
+    InvalidPadding,
+    /// The credentials did not include a secret for the element.
+    #[error("missing credentials for {0:?}")]
+    MissingCredentials(EncryptedPassportElementType),
+    /// The element does not have any encrypted data.
+    #[error("{0:?} element has no encrypted data")]
+    MissingData(EncryptedPassportElementType),
+}
+
+/// The bot's private RSA key, used to decrypt [EncryptedCredentials].
+#[derive(Clone)]
+pub struct PassportKey(RsaPrivateKey);
+
+impl PassportKey {
+    /// Load a private key from a PKCS#1 (`BEGIN RSA PRIVATE KEY`) or PKCS#8
+    /// (`BEGIN PRIVATE KEY`) PEM document.
+    pub fn from_pem(pem: &str) -> Result<Self, Error> {
+        RsaPrivateKey::from_pkcs1_pem(pem)
+            .or_else(|_| RsaPrivateKey::from_pkcs8_pem(pem))
+            .map(Self)
+            .map_err(|err| PassportError::Key(err.to_string()).into())
+    }
+}
+
+impl From<RsaPrivateKey> for PassportKey {
+    fn from(key: RsaPrivateKey) -> Self {
+        Self(key)
+    }
+}
+
+impl std::fmt::Debug for PassportKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PassportKey").finish_non_exhaustive()
+    }
+}
+
+/// Decrypted credentials for [PassportData](crate::PassportData).
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Credentials {
+    /// Secrets for each of the shared elements.
+    pub secure_data: SecureData,
+    /// The nonce provided when requesting the data. Must be checked to
+    /// ensure the data was requested by the bot.
+    pub nonce: String,
+}
+
+/// Secrets for the elements shared with the bot.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SecureData {
+    pub personal_details: Option<SecureValue>,
+    pub passport: Option<SecureValue>,
+    pub internal_passport: Option<SecureValue>,
+    pub driver_license: Option<SecureValue>,
+    pub identity_card: Option<SecureValue>,
+    pub address: Option<SecureValue>,
+    pub utility_bill: Option<SecureValue>,
+    pub bank_statement: Option<SecureValue>,
+    pub rental_agreement: Option<SecureValue>,
+    pub passport_registration: Option<SecureValue>,
+    pub temporary_registration: Option<SecureValue>,
+}
+
+impl SecureData {
+    /// Get the secrets for an element type. Phone numbers, emails, and
+    /// unknown element types never have secrets.
+    pub fn get(&self, element_type: EncryptedPassportElementType) -> Option<&SecureValue> {
+        use EncryptedPassportElementType::*;
+
+        match element_type {
+            PersonalDetails => self.personal_details.as_ref(),
+            Passport => self.passport.as_ref(),
+            InternalPassport => self.internal_passport.as_ref(),
+            DriverLicense => self.driver_license.as_ref(),
+            IdentityCard => self.identity_card.as_ref(),
+            Address => self.address.as_ref(),
+            UtilityBill => self.utility_bill.as_ref(),
+            BankStatement => self.bank_statement.as_ref(),
+            RentalAgreement => self.rental_agreement.as_ref(),
+            PassportRegistration => self.passport_registration.as_ref(),
+            TemporaryRegistration => self.temporary_registration.as_ref(),
+            PhoneNumber | Email | Unknown => None,
+        }
+    }
+}
+
+/// Secrets for the data and files of an element.
+///
+/// Each field matches the field of the same name on
+/// [EncryptedPassportElement].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SecureValue {
+    pub data: Option<DataCredentials>,
+    pub front_side: Option<FileCredentials>,
+    pub reverse_side: Option<FileCredentials>,
+    pub selfie: Option<FileCredentials>,
+    pub translation: Option<Vec<FileCredentials>>,
+    pub files: Option<Vec<FileCredentials>>,
+}
+
+/// Secret for the data field of an element.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DataCredentials {
+    pub data_hash: String,
+    pub secret: String,
+}
+
+impl DataCredentials {
+    /// Decrypt the base64-encoded data field of an element.
+    pub fn decrypt<T: DeserializeOwned>(&self, data: &str) -> Result<T, Error> {
+        let data = decrypt(
+            &STANDARD.decode(data).map_err(PassportError::from)?,
+            &STANDARD
+                .decode(&self.data_hash)
+                .map_err(PassportError::from)?,
+            &STANDARD.decode(&self.secret).map_err(PassportError::from)?,
+        )?;
+
+        Ok(serde_json::from_slice(&data)?)
+    }
+}
+
+/// Secret for a [PassportFile](crate::PassportFile).
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FileCredentials {
+    pub file_hash: String,
+    pub secret: String,
+}
+
+impl FileCredentials {
+    /// Decrypt the contents of a file downloaded with
+    /// [Telegram::download_file](crate::Telegram::download_file).
+    pub fn decrypt(&self, file: &[u8]) -> Result<Vec<u8>, Error> {
+        Ok(decrypt(
+            file,
+            &STANDARD
+                .decode(&self.file_hash)
+                .map_err(PassportError::from)?,
+            &STANDARD.decode(&self.secret).map_err(PassportError::from)?,
+        )?)
+    }
+}
+
+/// The user's personal details.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PersonalDetails {
+    pub first_name: String,
+    pub last_name: String,
+    pub middle_name: Option<String>,
+    /// Date of birth in DD.MM.YYYY format.
+    pub birth_date: String,
+    /// Gender, `male` or `female`.
+    pub gender: String,
+    /// Citizenship as a two-letter ISO 3166-1 alpha-2 country code.
+    pub country_code: String,
+    /// Country of residence as a two-letter ISO 3166-1 alpha-2 country code.
+    pub residence_country_code: String,
+    /// First name in the language of the user's country of residence.
+    pub first_name_native: Option<String>,
+    /// Last name in the language of the user's country of residence.
+    pub last_name_native: Option<String>,
+    /// Middle name in the language of the user's country of residence.
+    pub middle_name_native: Option<String>,
+}
+
+/// Data from an identity document.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct IdDocumentData {
+    pub document_no: String,
+    /// Expiration date in DD.MM.YYYY format.
+    pub expiry_date: Option<String>,
+}
+
+/// The user's residential address.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ResidentialAddress {
+    pub street_line1: String,
+    pub street_line2: Option<String>,
+    pub city: String,
+    pub state: Option<String>,
+    /// Two-letter ISO 3166-1 alpha-2 country code.
+    pub country_code: String,
+    pub post_code: String,
+}
+
+/// Decrypted data from an [EncryptedPassportElement].
+#[derive(Clone, Debug)]
+pub enum PassportElementData {
+    PersonalDetails(PersonalDetails),
+    IdDocument(IdDocumentData),
+    Address(ResidentialAddress),
+}
+
+impl EncryptedCredentials {
+    /// Decrypt the credentials using the bot's private key.
+    pub fn decrypt(&self, key: &PassportKey) -> Result<Credentials, Error> {
+        let secret = key
+            .0
+            .decrypt(
+                Oaep::new::<sha1::Sha1>(),
+                &STANDARD.decode(&self.secret).map_err(PassportError::from)?,
+            )
+            .map_err(PassportError::from)?;
+
+        let data = decrypt(
+            &STANDARD.decode(&self.data).map_err(PassportError::from)?,
+            &STANDARD.decode(&self.hash).map_err(PassportError::from)?,
+            &secret,
+        )?;
+
+        Ok(serde_json::from_slice(&data)?)
+    }
+}
+
+impl EncryptedPassportElement {
+    /// Decrypt the data field of a `personal_details`, `passport`,
+    /// `driver_license`, `identity_card`, `internal_passport`, or `address`
+    /// element.
+    pub fn decrypt_data(&self, credentials: &Credentials) -> Result<PassportElementData, Error> {
+        use EncryptedPassportElementType::*;
+
+        // Other elements, including types not supported by this library,
+        // never have encrypted data.
+        let decode: fn(&DataCredentials, &str) -> Result<PassportElementData, Error> =
+            match self.element_type {
+                PersonalDetails => {
+                    |creds, data| Ok(PassportElementData::PersonalDetails(creds.decrypt(data)?))
+                }
+                Passport | DriverLicense | IdentityCard | InternalPassport => {
+                    |creds, data| Ok(PassportElementData::IdDocument(creds.decrypt(data)?))
+                }
+                Address => |creds, data| Ok(PassportElementData::Address(creds.decrypt(data)?)),
+                element_type => return Err(PassportError::MissingData(element_type).into()),
+            };
+
+        let data = self
+            .data
+            .as_deref()
+            .ok_or(PassportError::MissingData(self.element_type))?;
+
+        let data_credentials = credentials
+            .secure_data
+            .get(self.element_type)
+            .and_then(|value| value.data.as_ref())
+            .ok_or(PassportError::MissingCredentials(self.element_type))?;
+
+        decode(data_credentials, data)
+    }
+}
+
+/// Decrypt data using AES-256-CBC with a key and IV derived from the secret
+/// and hash, then verify the hash and remove the random padding.
+fn decrypt(data: &[u8], hash: &[u8], secret: &[u8]) -> Result<Vec<u8>, PassportError> {
+    let digest = Sha512::new()
+        .chain_update(secret)
+        .chain_update(hash)
+        .finalize();
+
+    let mut data = data.to_vec();
+    Aes256CbcDec::new_from_slices(&digest[0..32], &digest[32..48])
+        .map_err(|_| PassportError::InvalidLength)?
+        .decrypt_padded_mut::<NoPadding>(&mut data)
+        .map_err(|_| PassportError::InvalidLength)?;
+
+    if Sha256::digest(&data).as_slice() != hash {
+        return Err(PassportError::HashMismatch);
+    }
+
+    let padding = *data.first().ok_or(PassportError::InvalidPadding)? as usize;
+    if padding < 32 || padding > data.len() {
+        return Err(PassportError::InvalidPadding);
+    }
+
+    data.drain(..padding);
+    Ok(data)
+}
@@ -0,0 +1,172 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use tracing::trace;
+
+use crate::requests::ChatID;
+
+/// A number of requests allowed within a period of time.
+///
+/// The number of requests must be at least 1.
+#[derive(Clone, Copy, Debug)]
+pub struct RateLimit {
+    /// Number of requests allowed within the period.
+    pub requests: usize,
+    /// Length of the period.
+    pub period: Duration,
+}
+
+impl RateLimit {
+    /// Allow up to `requests` requests within any window of length `period`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `requests` is 0.
+    pub fn new(requests: usize, period: Duration) -> Self {
+        assert!(requests > 0, "rate limit must allow at least one request");
+
+        Self { requests, period }
+    }
+}
+
+/// Throttles requests that send messages to stay under Telegram's limits.
+///
+/// Only requests that create messages (`send*` other than `sendChatAction`,
+/// `forward*`, and `copy*`) are throttled. Chats with a positive ID are
+/// treated as private chats, everything else as groups or channels.
+///
+/// The defaults follow Telegram's documented limits of 30 messages per second
+/// overall, 1 message per second to a private chat, and 20 messages per minute
+/// to a group.
+#[derive(Debug)]
+pub struct RateLimiter {
+    global: RateLimit,
+    private_chat: RateLimit,
+    group_chat: RateLimit,
+
+    state: Mutex<RateLimiterState>,
+}
+
+#[derive(Debug, Default)]
+struct RateLimiterState {
+    global: VecDeque<Instant>,
+    chats: HashMap<ChatID, VecDeque<Instant>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(
+            RateLimit::new(30, Duration::from_secs(1)),
+            RateLimit::new(1, Duration::from_secs(1)),
+            RateLimit::new(20, Duration::from_secs(60)),
+        )
+    }
+}
+
+impl RateLimiter {
+    /// Create a new rate limiter with custom limits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any limit allows 0 requests.
+    pub fn new(global: RateLimit, private_chat: RateLimit, group_chat: RateLimit) -> Self {
+        for limit in [global, private_chat, group_chat] {
+            assert!(
+                limit.requests > 0,
+                "rate limit must allow at least one request"
+            );
+        }
+
+        Self {
+            global,
+            private_chat,
+            group_chat,
+            state: Default::default(),
+        }
+    }
+
+    /// Wait until a request to the endpoint with the given values may be sent.
+    pub(crate) async fn wait(&self, endpoint: &str, values: &serde_json::Value) {
+        if !Self::is_throttled(endpoint) {
+            return;
+        }
+
+        let chat_id = values.get("chat_id").and_then(ChatID::from_value);
+
+        while let Some(delay) = self.reserve(chat_id.as_ref(), Instant::now()) {
+            trace!("Rate limited, waiting {:?}", delay);
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// If the request is one that sends a message.
+    fn is_throttled(endpoint: &str) -> bool {
+        (endpoint.starts_with("send") && endpoint != "sendChatAction")
+            || endpoint.starts_with("forward")
+            || endpoint.starts_with("copy")
+    }
+
+    fn chat_limit(&self, chat_id: &ChatID) -> RateLimit {
+        match chat_id {
+            ChatID::Identifier(id) if *id > 0 => self.private_chat,
+            _ => self.group_chat,
+        }
+    }
+
+    /// Attempt to reserve a request at the current time. Returns the amount
+    /// of time to wait before trying again if a limit would be exceeded.
+    fn reserve(&self, chat_id: Option<&ChatID>, now: Instant) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap();
+
+        let longest_period = self.private_chat.period.max(self.group_chat.period);
+        state.chats.retain(|_, sends| {
+            sends
+                .back()
+                .map(|last| now.duration_since(*last) < longest_period)
+                .unwrap_or(false)
+        });
+
+        let mut delay = Self::delay(&mut state.global, self.global, now);
+
+        if let Some(chat_id) = chat_id {
+            let limit = self.chat_limit(chat_id);
+            let sends = state.chats.entry(chat_id.clone()).or_default();
+            delay = delay.max(Self::delay(sends, limit, now));
+        }
+
+        if delay.is_some() {
+            return delay;
+        }
+
+        state.global.push_back(now);
+        if let Some(chat_id) = chat_id {
+            state
+                .chats
+                .entry(chat_id.clone())
+                .or_default()
+                .push_back(now);
+        }
+
+        None
+    }
+
+    /// Remove sends outside of the limit's period and calculate how long to
+    /// wait until another request is allowed.
+    fn delay(sends: &mut VecDeque<Instant>, limit: RateLimit, now: Instant) -> Option<Duration> {
+        while let Some(sent) = sends.front() {
+            if now.duration_since(*sent) >= limit.period {
+                sends.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if sends.len() < limit.requests {
+            return None;
+        }
+
+        let oldest = sends.get(sends.len() - limit.requests)?;
+        Some(limit.period - now.duration_since(*oldest))
+    }
+}
@@ -5,7 +5,7 @@ use crate::types::*;
 use crate::{RequestFiles, TelegramRequest};
 
 /// ChatID represents a possible type of value for requests.
-#[derive(Serialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 #[serde(untagged)]
 pub enum ChatID {
     /// A chat's numeric ID.
@@ -14,6 +14,17 @@ pub enum ChatID {
     Username(String),
 }
 
+impl ChatID {
+    /// Get a ChatID from a request's serialized `chat_id` value.
+    pub(crate) fn from_value(value: &serde_json::Value) -> Option<Self> {
+        match value {
+            serde_json::Value::Number(id) => id.as_i64().map(ChatID::Identifier),
+            serde_json::Value::String(username) => Some(ChatID::Username(username.clone())),
+            _ => None,
+        }
+    }
+}
+
 impl Message {
     pub fn chat_id(&self) -> ChatID {
         ChatID::Identifier(self.chat.id)
@@ -75,21 +86,223 @@ impl Default for ForceReply {
     }
 }
 
+/// ReplyKeyboardRemove removes a custom keyboard previously sent with a
+/// [ReplyKeyboardMarkup].
+#[derive(Serialize, Debug, Clone)]
+pub struct ReplyKeyboardRemove {
+    /// This must be set to `true` to operate correctly.
+    pub remove_keyboard: bool,
+    /// If the keyboard should only be removed for the users you are mentioning
+    /// or replying to, or if it should be removed for all members of the chat.
+    pub selective: bool,
+}
+
+impl ReplyKeyboardRemove {
+    /// Create a [ReplyKeyboardRemove] with selectivity.
+    pub fn selective() -> Self {
+        Self {
+            remove_keyboard: true,
+            selective: true,
+        }
+    }
+}
+
+impl Default for ReplyKeyboardRemove {
+    /// Create a [ReplyKeyboardRemove] without selectivity.
+    fn default() -> Self {
+        Self {
+            remove_keyboard: true,
+            selective: false,
+        }
+    }
+}
+
+/// ReplyKeyboardMarkup replaces the user's keyboard with a custom keyboard.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ReplyKeyboardMarkup {
+    /// Rows of buttons to display.
+    pub keyboard: Vec<Vec<KeyboardButton>>,
+    /// If the keyboard should always be shown when the regular keyboard is
+    /// hidden. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_persistent: Option<bool>,
+    /// If the keyboard should be resized to fit its buttons. Defaults to
+    /// false, which makes the keyboard the same height as the regular keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resize_keyboard: Option<bool>,
+    /// If the keyboard should be hidden after a button is pressed. It is still
+    /// available through the keyboard icon. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one_time_keyboard: Option<bool>,
+    /// Placeholder shown in the input field while the keyboard is active, may
+    /// be 1-64 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_field_placeholder: Option<String>,
+    /// If the keyboard should only be shown to the users you are mentioning or
+    /// replying to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selective: Option<bool>,
+}
+
+/// A button on a [ReplyKeyboardMarkup].
+///
+/// At most one of the optional fields may be set. If none are set, the text of
+/// the button is sent as a message when pressed.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct KeyboardButton {
+    /// Text of the button.
+    pub text: String,
+    /// Ask the user to select users, which are sent in a `users_shared`
+    /// service message. Only available in private chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_users: Option<KeyboardButtonRequestUsers>,
+    /// Ask the user to select a chat, which is sent in a `chat_shared` service
+    /// message. Only available in private chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_chat: Option<KeyboardButtonRequestChat>,
+    /// Send the user's phone number as a contact. Only available in private
+    /// chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_contact: Option<bool>,
+    /// Send the user's current location. Only available in private chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_location: Option<bool>,
+    /// Ask the user to create a poll and send it to the bot. Only available in
+    /// private chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_poll: Option<KeyboardButtonPollType>,
+    /// Open a Web App, which may send data back to the bot. Only available in
+    /// private chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_app: Option<WebAppInfo>,
+}
+
+impl KeyboardButton {
+    /// Create a [KeyboardButton] that sends its text when pressed.
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Criteria for users selected from a [KeyboardButton].
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct KeyboardButtonRequestUsers {
+    /// Identifier of the request, returned in the `users_shared` message.
+    /// Must be unique within the message.
+    pub request_id: i32,
+    /// If set, only bots (`true`) or only regular users (`false`) may be
+    /// selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_is_bot: Option<bool>,
+    /// If set, only premium (`true`) or non-premium (`false`) users may be
+    /// selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_is_premium: Option<bool>,
+    /// Maximum number of users to be selected, may be 1-10. Defaults to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_quantity: Option<i32>,
+    /// If the users' names should be requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_name: Option<bool>,
+    /// If the users' usernames should be requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_username: Option<bool>,
+    /// If the users' photos should be requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_photo: Option<bool>,
+}
+
+/// Criteria for a chat selected from a [KeyboardButton].
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct KeyboardButtonRequestChat {
+    /// Identifier of the request, returned in the `chat_shared` message.
+    /// Must be unique within the message.
+    pub request_id: i32,
+    /// If a channel (`true`) or a group (`false`) should be selected.
+    pub chat_is_channel: bool,
+    /// If set, only forums (`true`) or only non-forums (`false`) may be
+    /// selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_is_forum: Option<bool>,
+    /// If set, only chats with (`true`) or without (`false`) a username may
+    /// be selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_has_username: Option<bool>,
+    /// If only chats owned by the user may be selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_is_created: Option<bool>,
+    /// Rights the user must have in the chat.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_administrator_rights: Option<ChatAdministratorRights>,
+    /// Rights the bot must have in the chat.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_administrator_rights: Option<ChatAdministratorRights>,
+    /// If only chats with the bot as a member may be selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_is_member: Option<bool>,
+    /// If the chat's title should be requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_title: Option<bool>,
+    /// If the chat's username should be requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_username: Option<bool>,
+    /// If the chat's photo should be requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_photo: Option<bool>,
+}
+
+/// The type of poll a user may create from a [KeyboardButton].
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct KeyboardButtonPollType {
+    /// If set, the user may only create polls of this type.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub poll_type: Option<PollType>,
+}
+
 /// ReplyMarkup is additional data sent with a [Message] to enhance the bot
 /// user experience.
 ///
 /// You may add one of the following:
 /// * [InlineKeyboardMarkup]
-/// * <s>ReplyKeyboardMarkup</s> // TODO
-/// * <s>ReplyKeyboardRemove</s> // TODO
+/// * [ReplyKeyboardMarkup]
+/// * [ReplyKeyboardRemove]
 /// * [ForceReply]
 #[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum ReplyMarkup {
     InlineKeyboardMarkup(InlineKeyboardMarkup),
+    ReplyKeyboardMarkup(ReplyKeyboardMarkup),
+    ReplyKeyboardRemove(ReplyKeyboardRemove),
     ForceReply(ForceReply),
 }
 
+impl From<InlineKeyboardMarkup> for ReplyMarkup {
+    fn from(markup: InlineKeyboardMarkup) -> Self {
+        ReplyMarkup::InlineKeyboardMarkup(markup)
+    }
+}
+
+impl From<ReplyKeyboardMarkup> for ReplyMarkup {
+    fn from(markup: ReplyKeyboardMarkup) -> Self {
+        ReplyMarkup::ReplyKeyboardMarkup(markup)
+    }
+}
+
+impl From<ReplyKeyboardRemove> for ReplyMarkup {
+    fn from(markup: ReplyKeyboardRemove) -> Self {
+        ReplyMarkup::ReplyKeyboardRemove(markup)
+    }
+}
+
+impl From<ForceReply> for ReplyMarkup {
+    fn from(markup: ForceReply) -> Self {
+        ReplyMarkup::ForceReply(markup)
+    }
+}
+
 /// Mode that Telegram uses to parse content from a [SendMessage].
 #[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "PascalCase")]
@@ -242,28 +455,76 @@ pub struct InlineQueryResult {
     pub content: InlineQueryType,
 }
 
+/// The content of an [InlineQueryResult].
+///
+/// Cached variants reference a file already on Telegram's servers by its ID.
 #[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum InlineQueryType {
     Article(InlineQueryResultArticle),
     Photo(InlineQueryResultPhoto),
     Gif(InlineQueryResultGIF),
+    Mpeg4Gif(InlineQueryResultMPEG4GIF),
     Video(InlineQueryResultVideo),
+    Audio(InlineQueryResultAudio),
+    Voice(InlineQueryResultVoice),
+    Document(InlineQueryResultDocument),
+    Location(InlineQueryResultLocation),
+    Venue(InlineQueryResultVenue),
+    Contact(InlineQueryResultContact),
+    Game(InlineQueryResultGame),
+    CachedPhoto(InlineQueryResultCachedPhoto),
+    CachedGif(InlineQueryResultCachedGIF),
+    CachedMpeg4Gif(InlineQueryResultCachedMPEG4GIF),
+    CachedSticker(InlineQueryResultCachedSticker),
+    CachedDocument(InlineQueryResultCachedDocument),
+    CachedVideo(InlineQueryResultCachedVideo),
+    CachedVoice(InlineQueryResultCachedVoice),
+    CachedAudio(InlineQueryResultCachedAudio),
+}
+
+impl InlineQueryType {
+    /// The value of the `type` field for this kind of result.
+    pub fn result_type(&self) -> &'static str {
+        match self {
+            InlineQueryType::Article(_) => "article",
+            InlineQueryType::Photo(_) | InlineQueryType::CachedPhoto(_) => "photo",
+            InlineQueryType::Gif(_) | InlineQueryType::CachedGif(_) => "gif",
+            InlineQueryType::Mpeg4Gif(_) | InlineQueryType::CachedMpeg4Gif(_) => "mpeg4_gif",
+            InlineQueryType::Video(_) | InlineQueryType::CachedVideo(_) => "video",
+            InlineQueryType::Audio(_) | InlineQueryType::CachedAudio(_) => "audio",
+            InlineQueryType::Voice(_) | InlineQueryType::CachedVoice(_) => "voice",
+            InlineQueryType::Document(_) | InlineQueryType::CachedDocument(_) => "document",
+            InlineQueryType::Location(_) => "location",
+            InlineQueryType::Venue(_) => "venue",
+            InlineQueryType::Contact(_) => "contact",
+            InlineQueryType::Game(_) => "game",
+            InlineQueryType::CachedSticker(_) => "sticker",
+        }
+    }
 }
 
 #[derive(Serialize, Debug, Clone, Default)]
 pub struct InlineQueryResultArticle {
     pub title: String,
-    #[serde(flatten)]
     pub input_message_content: InputMessageType,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<u32>,
 }
 
 #[derive(Serialize, Debug, Clone, Default)]
 pub struct InlineQueryResultPhoto {
+    /// URL of the photo, must be a JPEG of up to 5MB.
     pub photo_url: String,
-    pub thumb_url: String,
+    pub thumbnail_url: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub photo_width: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -276,713 +537,3485 @@ pub struct InlineQueryResultPhoto {
     pub caption: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Content sent instead of the photo.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
 }
 
 #[derive(Serialize, Debug, Clone, Default)]
 pub struct InlineQueryResultGIF {
+    /// URL of the GIF, may be up to 1MB.
     pub gif_url: String,
-    pub thumb_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gif_width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gif_height: Option<u32>,
+    /// Duration of the GIF in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gif_duration: Option<u32>,
+    /// URL of a JPEG, GIF, or MPEG4 thumbnail.
+    pub thumbnail_url: String,
+    /// MIME type of the thumbnail, may be `image/jpeg`, `image/gif`, or
+    /// `video/mp4`. Defaults to `image/jpeg`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Content sent instead of the GIF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+}
+
+/// A link to a silent H.264/MPEG-4 AVC video.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultMPEG4GIF {
+    /// URL of the video, may be up to 1MB.
+    pub mpeg4_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_height: Option<u32>,
+    /// Duration of the video in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_duration: Option<u32>,
+    /// URL of a JPEG, GIF, or MPEG4 thumbnail.
+    pub thumbnail_url: String,
+    /// MIME type of the thumbnail, may be `image/jpeg`, `image/gif`, or
+    /// `video/mp4`. Defaults to `image/jpeg`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Content sent instead of the video.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
 }
 
 #[derive(Serialize, Debug, Clone, Default)]
 pub struct InlineQueryResultVideo {
     pub video_url: String,
+    /// MIME type of the video, may be `text/html` or `video/mp4`.
     pub mime_type: String,
-    pub thumb_url: String,
+    /// URL of a JPEG thumbnail.
+    pub thumbnail_url: String,
     pub title: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_height: Option<u32>,
+    /// Duration of the video in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_duration: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Content sent instead of the video. Required if the video is embedded
+    /// HTML content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
 }
 
-impl InlineQueryResult {
-    pub fn article(id: String, title: String, text: String) -> InlineQueryResult {
-        InlineQueryResult {
-            result_type: "article".into(),
-            id,
-            reply_markup: None,
-            content: InlineQueryType::Article(InlineQueryResultArticle {
-                title,
-                description: None,
-                input_message_content: InputMessageType::Text(InputMessageText {
-                    message_text: text,
-                    parse_mode: None,
-                }),
-            }),
-        }
-    }
-
-    pub fn photo(id: String, photo_url: String, thumb_url: String) -> InlineQueryResult {
-        InlineQueryResult {
-            result_type: "photo".into(),
-            id,
-            reply_markup: None,
-            content: InlineQueryType::Photo(InlineQueryResultPhoto {
-                photo_url,
-                thumb_url,
-                caption: None,
-                ..Default::default()
-            }),
-        }
-    }
+/// A link to an MP3 audio file.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultAudio {
+    pub audio_url: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performer: Option<String>,
+    /// Duration of the audio in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_duration: Option<u32>,
+    /// Content sent instead of the audio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+}
 
-    pub fn gif(id: String, gif_url: String, thumb_url: String) -> InlineQueryResult {
-        InlineQueryResult {
-            result_type: "gif".into(),
-            id,
-            reply_markup: None,
-            content: InlineQueryType::Gif(InlineQueryResultGIF {
-                gif_url,
-                thumb_url,
-                caption: None,
-            }),
-        }
-    }
+/// A link to a voice recording in an OGG container encoded with OPUS.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultVoice {
+    pub voice_url: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Duration of the recording in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_duration: Option<u32>,
+    /// Content sent instead of the recording.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+}
 
-    pub fn video(
-        id: String,
-        video_url: String,
-        mime_type: String,
-        thumb_url: String,
-        title: String,
-    ) -> InlineQueryResult {
-        InlineQueryResult {
-            result_type: "video".into(),
-            id,
-            reply_markup: None,
-            content: InlineQueryType::Video(InlineQueryResultVideo {
-                video_url,
-                mime_type,
-                thumb_url,
-                title,
-                caption: None,
-            }),
-        }
-    }
+/// A link to a PDF or ZIP file.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultDocument {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    pub document_url: String,
+    /// MIME type of the file, may be `application/pdf` or `application/zip`.
+    pub mime_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Content sent instead of the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<u32>,
 }
 
-#[derive(Serialize, Debug, Clone)]
-#[serde(untagged)]
-pub enum InputMessageType {
-    Text(InputMessageText),
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultLocation {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub title: String,
+    /// Radius of uncertainty for the location in meters, may be 0-1500.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+    /// Number of seconds the location can be updated for live locations,
+    /// may be 60-86400.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i32>,
+    /// Direction the user is moving in degrees for live locations, may be
+    /// 1-360.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i32>,
+    /// Maximum distance in meters for proximity alerts about approaching
+    /// another chat member for live locations, may be 1-100000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i32>,
+    /// Content sent instead of the location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<u32>,
 }
 
-impl Default for InputMessageType {
-    fn default() -> Self {
-        InputMessageType::Text(Default::default())
-    }
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultVenue {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub title: String,
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<String>,
+    /// Content sent instead of the venue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<u32>,
 }
 
 #[derive(Serialize, Debug, Clone, Default)]
-pub struct InputMessageText {
-    pub message_text: String,
+pub struct InlineQueryResultContact {
+    pub phone_number: String,
+    pub first_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub last_name: Option<String>,
+    /// Additional data about the contact as a vCard, may be 0-2048 bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+    /// Content sent instead of the contact.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<u32>,
 }
 
-/// GetMe is a request that returns [User] information for the current bot.
-#[derive(Serialize, Debug, Clone)]
-pub struct GetMe;
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultGame {
+    pub game_short_name: String,
+}
 
-impl TelegramRequest for GetMe {
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultCachedPhoto {
+    pub photo_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultCachedGIF {
+    pub gif_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultCachedMPEG4GIF {
+    pub mpeg4_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultCachedSticker {
+    pub sticker_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultCachedDocument {
+    pub title: String,
+    pub document_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultCachedVideo {
+    pub video_file_id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultCachedVoice {
+    pub voice_file_id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InlineQueryResultCachedAudio {
+    pub audio_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageType>,
+}
+
+impl InlineQueryResult {
+    /// Create a result of any type, with the `type` field set to match the
+    /// content.
+    pub fn new(id: String, content: InlineQueryType) -> InlineQueryResult {
+        InlineQueryResult {
+            result_type: content.result_type().into(),
+            id,
+            reply_markup: None,
+            content,
+        }
+    }
+
+    pub fn article(id: String, title: String, text: String) -> InlineQueryResult {
+        Self::new(
+            id,
+            InlineQueryType::Article(InlineQueryResultArticle {
+                title,
+                input_message_content: InputMessageType::Text(InputMessageText {
+                    message_text: text,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        )
+    }
+
+    pub fn photo(id: String, photo_url: String, thumbnail_url: String) -> InlineQueryResult {
+        Self::new(
+            id,
+            InlineQueryType::Photo(InlineQueryResultPhoto {
+                photo_url,
+                thumbnail_url,
+                ..Default::default()
+            }),
+        )
+    }
+
+    pub fn gif(id: String, gif_url: String, thumbnail_url: String) -> InlineQueryResult {
+        Self::new(
+            id,
+            InlineQueryType::Gif(InlineQueryResultGIF {
+                gif_url,
+                thumbnail_url,
+                ..Default::default()
+            }),
+        )
+    }
+
+    pub fn mpeg4_gif(id: String, mpeg4_url: String, thumbnail_url: String) -> InlineQueryResult {
+        Self::new(
+            id,
+            InlineQueryType::Mpeg4Gif(InlineQueryResultMPEG4GIF {
+                mpeg4_url,
+                thumbnail_url,
+                ..Default::default()
+            }),
+        )
+    }
+
+    pub fn video(
+        id: String,
+        video_url: String,
+        mime_type: String,
+        thumbnail_url: String,
+        title: String,
+    ) -> InlineQueryResult {
+        Self::new(
+            id,
+            InlineQueryType::Video(InlineQueryResultVideo {
+                video_url,
+                mime_type,
+                thumbnail_url,
+                title,
+                ..Default::default()
+            }),
+        )
+    }
+
+    pub fn audio(id: String, audio_url: String, title: String) -> InlineQueryResult {
+        Self::new(
+            id,
+            InlineQueryType::Audio(InlineQueryResultAudio {
+                audio_url,
+                title,
+                ..Default::default()
+            }),
+        )
+    }
+
+    pub fn voice(id: String, voice_url: String, title: String) -> InlineQueryResult {
+        Self::new(
+            id,
+            InlineQueryType::Voice(InlineQueryResultVoice {
+                voice_url,
+                title,
+                ..Default::default()
+            }),
+        )
+    }
+
+    pub fn document(
+        id: String,
+        title: String,
+        document_url: String,
+        mime_type: String,
+    ) -> InlineQueryResult {
+        Self::new(
+            id,
+            InlineQueryType::Document(InlineQueryResultDocument {
+                title,
+                document_url,
+                mime_type,
+                ..Default::default()
+            }),
+        )
+    }
+
+    pub fn game(id: String, game_short_name: String) -> InlineQueryResult {
+        Self::new(
+            id,
+            InlineQueryType::Game(InlineQueryResultGame { game_short_name }),
+        )
+    }
+
+    pub fn cached_photo(id: String, photo_file_id: String) -> InlineQueryResult {
+        Self::new(
+            id,
+            InlineQueryType::CachedPhoto(InlineQueryResultCachedPhoto {
+                photo_file_id,
+                ..Default::default()
+            }),
+        )
+    }
+
+    pub fn cached_sticker(id: String, sticker_file_id: String) -> InlineQueryResult {
+        Self::new(
+            id,
+            InlineQueryType::CachedSticker(InlineQueryResultCachedSticker {
+                sticker_file_id,
+                ..Default::default()
+            }),
+        )
+    }
+}
+
+/// Content of a message sent as the result of an inline query.
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum InputMessageType {
+    Text(InputMessageText),
+    Location(InputLocationMessageContent),
+    Venue(InputVenueMessageContent),
+    Contact(InputContactMessageContent),
+    Invoice(InputInvoiceMessageContent),
+}
+
+impl Default for InputMessageType {
+    fn default() -> Self {
+        InputMessageType::Text(Default::default())
+    }
+}
+
+impl From<InputMessageText> for InputMessageType {
+    fn from(content: InputMessageText) -> Self {
+        InputMessageType::Text(content)
+    }
+}
+
+impl From<InputLocationMessageContent> for InputMessageType {
+    fn from(content: InputLocationMessageContent) -> Self {
+        InputMessageType::Location(content)
+    }
+}
+
+impl From<InputVenueMessageContent> for InputMessageType {
+    fn from(content: InputVenueMessageContent) -> Self {
+        InputMessageType::Venue(content)
+    }
+}
+
+impl From<InputContactMessageContent> for InputMessageType {
+    fn from(content: InputContactMessageContent) -> Self {
+        InputMessageType::Contact(content)
+    }
+}
+
+impl From<InputInvoiceMessageContent> for InputMessageType {
+    fn from(content: InputInvoiceMessageContent) -> Self {
+        InputMessageType::Invoice(content)
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InputMessageText {
+    pub message_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_preview_options: Option<LinkPreviewOptions>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InputLocationMessageContent {
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Radius of uncertainty for the location in meters, may be 0-1500.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+    /// Number of seconds the location can be updated for live locations,
+    /// may be 60-86400.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i32>,
+    /// Direction the user is moving in degrees for live locations, may be
+    /// 1-360.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i32>,
+    /// Maximum distance in meters for proximity alerts about approaching
+    /// another chat member for live locations, may be 1-100000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i32>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InputVenueMessageContent {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub title: String,
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InputContactMessageContent {
+    pub phone_number: String,
+    pub first_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Additional data about the contact as a vCard, may be 0-2048 bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+}
+
+/// A portion of the price for goods or services.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct LabeledPrice {
+    pub label: String,
+    /// Price in the smallest units of the currency, such as cents for `USD`.
+    pub amount: i32,
+}
+
+impl LabeledPrice {
+    pub fn new(label: String, amount: i32) -> Self {
+        Self { label, amount }
+    }
+}
+
+/// Currency code for payments in Telegram Stars.
+///
+/// Invoices in Telegram Stars must have exactly one price, no provider token,
+/// and cannot request shipping or other order information.
+pub const STARS_CURRENCY: &str = "XTR";
+
+/// Details of an invoice, shared by [InputInvoiceMessageContent],
+/// [SendInvoice], and [CreateInvoiceLink].
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InvoiceDetails {
+    /// Product name, may be 1-32 characters.
+    pub title: String,
+    /// Product description, may be 1-255 characters.
+    pub description: String,
+    /// Bot-defined payload, may be 1-128 bytes. This is not shown to the
+    /// user.
+    pub payload: String,
+    /// Payment provider token from BotFather. Not needed for payments in
+    /// Telegram Stars.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_token: Option<String>,
+    /// Three-letter ISO 4217 currency code.
+    pub currency: String,
+    pub prices: Vec<LabeledPrice>,
+    /// Maximum tip in the smallest units of the currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tip_amount: Option<i32>,
+    /// Suggested tips in the smallest units of the currency, at most 4
+    /// strictly increasing amounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_tip_amounts: Option<Vec<i32>>,
+    /// JSON-serialized data for the payment provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_name: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_phone_number: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_email: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_shipping_address: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_phone_number_to_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_email_to_provider: Option<bool>,
+    /// If the final price depends on the shipping method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_flexible: Option<bool>,
+}
+
+impl InvoiceDetails {
+    /// Create details for an invoice paid in Telegram Stars, with a single
+    /// price for the whole amount.
+    pub fn stars(title: String, description: String, payload: String, amount: i32) -> Self {
+        Self {
+            prices: vec![LabeledPrice::new(title.clone(), amount)],
+            title,
+            description,
+            payload,
+            currency: STARS_CURRENCY.into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InputInvoiceMessageContent {
+    #[serde(flatten)]
+    pub invoice: InvoiceDetails,
+}
+
+/// GetMe is a request that returns [User] information for the current bot.
+#[derive(Serialize, Debug, Clone)]
+pub struct GetMe;
+
+impl TelegramRequest for GetMe {
     type Response = User;
 
     fn endpoint(&self) -> &str {
-        "getMe"
+        "getMe"
+    }
+}
+
+/// GetUpdates is a request that returns any available [Updates](Update).
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct GetUpdates {
+    /// ID for the first update to return. This must be set to one higher
+    /// than previous IDs in order to confirm previous updates and clear them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+    /// Maximum number of [Updates](Update) to retrieve. May be set 1-100,
+    /// defaults to 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    /// Number of seconds for long polling. This should be set to a reasonable
+    /// value in production to avoid unneeded requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<i32>,
+    /// Which update types to receive. May be set to any available types.
+    /// * `message`
+    /// * `edited_message`
+    /// * `channel_post`
+    /// * `edited_channel_post`
+    /// * `inline_query`
+    /// * `chosen_inline_result`
+    /// * `callback_query`
+    /// * `shipping_query`
+    /// * `pre_checkout_query`
+    /// * `poll`
+    /// * `poll_answer`
+    /// * `my_chat_member`
+    /// * `chat_member`
+    /// * `chat_join_request`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_updates: Option<Vec<String>>,
+}
+
+impl TelegramRequest for GetUpdates {
+    type Response = Vec<Update>;
+
+    fn endpoint(&self) -> &str {
+        "getUpdates"
+    }
+}
+
+/// SendMessage sends a message.
+///
+/// # Example
+///
+/// ```
+/// # use tgbotapi::requests::{ChatID, SendMessage};
+/// let send_message = SendMessage {
+///     chat_id: ChatID::Identifier(12345),
+///     text: "Hello, world!".into(),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct SendMessage {
+    /// The ID of the chat to send a message to.
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    /// The text of the message. May be 1-4096 characters after entity parsing.
+    pub text: String,
+    /// The mode used to parse the provided text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    /// If Telegram should not generate a web page preview.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_web_page_preview: Option<bool>,
+    /// If the message should be sent silently (notification but no sound).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// The ID of the [Message] this Message is in reply to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    /// Allow sending the message even if the reply message was not found.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    /// The [ReplyMarkup], if desired.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for SendMessage {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "sendMessage"
+    }
+}
+
+/// SendChatAction allows you to indicate to users that the bot is performing
+/// an action.
+///
+/// Actions last for 5 seconds or until a message is sent,
+/// whichever comes first.
+#[derive(Serialize, Debug, Clone)]
+pub struct SendChatAction {
+    /// The ID of the chat to send an action to.
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    /// The action to indicate.
+    pub action: ChatAction,
+}
+
+impl TelegramRequest for SendChatAction {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "sendChatAction"
+    }
+}
+
+/// SendPhoto sends a photo.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SendPhoto {
+    /// The ID of the chat to send a photo to.
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    /// The file that makes up this photo.
+    #[serde(skip_serializing_if = "FileType::needs_upload")]
+    pub photo: FileType,
+    /// A caption for the photo, if desired.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// If the photo should be covered with a spoiler animation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    /// Allow sending the message even if the reply message was not found.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for SendPhoto {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "sendPhoto"
+    }
+
+    fn files(&self) -> RequestFiles {
+        // Check if the photo needs to be uploaded. If the photo does need to
+        // be uploaded, we specify the field name and get the file. This unwrap
+        // is safe because `needs_upload` only returns true when it exists.
+        if self.photo.needs_upload() {
+            Some(vec![("photo".into(), self.photo.file().unwrap())])
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SendDocument {
+    /// The ID of the chat to send a photo to.
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    /// The file that makes up this photo.
+    #[serde(skip_serializing_if = "FileType::needs_upload")]
+    pub document: FileType,
+    /// A caption for the photo, if desired.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for SendDocument {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "sendDocument"
+    }
+
+    fn files(&self) -> RequestFiles {
+        if self.document.needs_upload() {
+            Some(vec![("document".into(), self.document.file().unwrap())])
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SendVideo {
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    #[serde(skip_serializing_if = "FileType::needs_upload")]
+    pub video: FileType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports_streaming: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for SendVideo {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "sendVideo"
+    }
+
+    fn files(&self) -> RequestFiles {
+        if self.video.needs_upload() {
+            Some(vec![("video".into(), self.video.file().unwrap())])
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SendAnimation {
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    #[serde(skip_serializing_if = "FileType::needs_upload")]
+    pub animation: FileType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_spoiler: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for SendAnimation {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "sendAnimation"
+    }
+
+    fn files(&self) -> RequestFiles {
+        if self.animation.needs_upload() {
+            Some(vec![("animation".into(), self.animation.file().unwrap())])
+        } else {
+            None
+        }
+    }
+}
+
+/// SendAudio sends an audio file to be displayed in the music player.
+///
+/// Audio must be in MP3 or M4A format.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SendAudio {
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    #[serde(skip_serializing_if = "FileType::needs_upload")]
+    pub audio: FileType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Duration of the audio in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performer: Option<String>,
+    /// Track name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for SendAudio {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "sendAudio"
+    }
+
+    fn files(&self) -> RequestFiles {
+        if self.audio.needs_upload() {
+            Some(vec![("audio".into(), self.audio.file().unwrap())])
+        } else {
+            None
+        }
+    }
+}
+
+/// SendVoice sends an audio file to be displayed as a voice message.
+///
+/// Audio must be in OGG encoded with OPUS, MP3, or M4A format.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SendVoice {
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    #[serde(skip_serializing_if = "FileType::needs_upload")]
+    pub voice: FileType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    /// Duration of the voice message in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for SendVoice {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "sendVoice"
+    }
+
+    fn files(&self) -> RequestFiles {
+        if self.voice.needs_upload() {
+            Some(vec![("voice".into(), self.voice.file().unwrap())])
+        } else {
+            None
+        }
+    }
+}
+
+/// SendVideoNote sends a rounded square MP4 video of up to 1 minute.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SendVideoNote {
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    /// The video note. Sending video notes by URL is not supported.
+    #[serde(skip_serializing_if = "FileType::needs_upload")]
+    pub video_note: FileType,
+    /// Duration of the video in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i32>,
+    /// Width and height of the video.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for SendVideoNote {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "sendVideoNote"
+    }
+
+    fn files(&self) -> RequestFiles {
+        if self.video_note.needs_upload() {
+            Some(vec![("video_note".into(), self.video_note.file().unwrap())])
+        } else {
+            None
+        }
+    }
+}
+
+/// SendSticker sends a static WEBP, animated TGS, or video WEBM sticker.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SendSticker {
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    #[serde(skip_serializing_if = "FileType::needs_upload")]
+    pub sticker: FileType,
+    /// Emoji associated with a newly uploaded sticker.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for SendSticker {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "sendSticker"
+    }
+
+    fn files(&self) -> RequestFiles {
+        if self.sticker.needs_upload() {
+            Some(vec![("sticker".into(), self.sticker.file().unwrap())])
+        } else {
+            None
+        }
+    }
+}
+
+/// SendLocation sends a point on a map.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SendLocation {
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Radius of uncertainty for the location in meters, may be 0-1500.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+    /// Number of seconds the location can be updated for live locations,
+    /// may be 60-86400.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i32>,
+    /// Direction the user is moving in degrees for live locations, may be
+    /// 1-360.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i32>,
+    /// Maximum distance in meters for proximity alerts about approaching
+    /// another chat member for live locations, may be 1-100000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for SendLocation {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "sendLocation"
+    }
+}
+
+/// SendVenue sends information about a venue.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SendVenue {
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Name of the venue.
+    pub title: String,
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for SendVenue {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "sendVenue"
+    }
+}
+
+/// SendContact sends a phone contact.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SendContact {
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    pub phone_number: String,
+    pub first_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Additional data about the contact as a vCard, may be 0-2048 bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for SendContact {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "sendContact"
+    }
+}
+
+/// SendDice sends an animated emoji that displays a random value.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SendDice {
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    /// Emoji the dice animation is based on. May be one of 🎲, 🎯, 🏀, ⚽,
+    /// 🎳, or 🎰. Defaults to 🎲.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for SendDice {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "sendDice"
+    }
+}
+
+/// An answer option for a [SendPoll] request.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct InputPollOption {
+    /// Option text, may be 1-100 characters.
+    pub text: String,
+    /// Mode used to parse entities in the text. Only custom emoji entities
+    /// are allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_entities: Option<Vec<MessageEntity>>,
+}
+
+impl From<String> for InputPollOption {
+    fn from(text: String) -> Self {
+        Self {
+            text,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for InputPollOption {
+    fn from(text: &str) -> Self {
+        text.to_string().into()
+    }
+}
+
+/// SendPoll sends a native poll.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SendPoll {
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    /// Poll question, may be 1-300 characters.
+    pub question: String,
+    /// Answer options, may include 2-10 items.
+    pub options: Vec<InputPollOption>,
+    /// If the poll should be anonymous. Defaults to true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_anonymous: Option<bool>,
+    /// Type of the poll. Defaults to regular.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub poll_type: Option<PollType>,
+    /// If regular polls allow multiple answers. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allows_multiple_answers: Option<bool>,
+    /// 0-based index of the correct option, required for quizzes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correct_option_id: Option<i32>,
+    /// Text shown when a user selects an incorrect answer in a quiz, may be
+    /// 0-200 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation_parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation_entities: Option<Vec<MessageEntity>>,
+    /// Number of seconds the poll is active, may be 5-600. May not be used
+    /// with `close_date`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_period: Option<i32>,
+    /// Unix timestamp when the poll will be closed, must be 5-600 seconds in
+    /// the future. May not be used with `open_period`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_date: Option<i64>,
+    /// If the poll should be closed immediately.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_closed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for SendPoll {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "sendPoll"
+    }
+}
+
+/// ForwardMessage forwards a message of any kind.
+///
+/// Service messages and messages with protected content cannot be forwarded.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct ForwardMessage {
+    /// The ID of the chat to forward the message to.
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    /// The ID of the chat the original message was sent in.
+    pub from_chat_id: ChatID,
+    /// The ID of the message in the original chat.
+    pub message_id: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// If the forwarded message should be protected from forwarding and saving.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+}
+
+impl TelegramRequest for ForwardMessage {
+    type Response = Message;
+
+    fn endpoint(&self) -> &str {
+        "forwardMessage"
+    }
+}
+
+/// ForwardMessages forwards multiple messages of any kind.
+///
+/// Messages that cannot be forwarded are skipped. Album grouping is kept.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct ForwardMessages {
+    /// The ID of the chat to forward the messages to.
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    /// The ID of the chat the original messages were sent in.
+    pub from_chat_id: ChatID,
+    /// The IDs of the messages in the original chat, may include 1-100 items
+    /// in increasing order.
+    pub message_ids: Vec<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// If the forwarded messages should be protected from forwarding and saving.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+}
+
+impl TelegramRequest for ForwardMessages {
+    type Response = Vec<MessageId>;
+
+    fn endpoint(&self) -> &str {
+        "forwardMessages"
+    }
+}
+
+/// CopyMessage copies a message without a link to the original message.
+///
+/// Service messages, giveaways, invoices, and messages with protected content
+/// cannot be copied. A quiz poll may only be copied if the bot knows the
+/// correct option.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct CopyMessage {
+    /// The ID of the chat to copy the message to.
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    /// The ID of the chat the original message was sent in.
+    pub from_chat_id: ChatID,
+    /// The ID of the message in the original chat.
+    pub message_id: i32,
+    /// A new caption for media, may be 0-1024 characters. The original caption
+    /// is kept if not specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// If the copied message should be protected from forwarding and saving.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for CopyMessage {
+    type Response = MessageId;
+
+    fn endpoint(&self) -> &str {
+        "copyMessage"
+    }
+}
+
+/// CopyMessages copies multiple messages without links to the original
+/// messages.
+///
+/// Messages that cannot be copied are skipped. Album grouping is kept.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct CopyMessages {
+    /// The ID of the chat to copy the messages to.
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    /// The ID of the chat the original messages were sent in.
+    pub from_chat_id: ChatID,
+    /// The IDs of the messages in the original chat, may include 1-100 items
+    /// in increasing order.
+    pub message_ids: Vec<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    /// If the copied messages should be protected from forwarding and saving.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// If the messages should be copied without their captions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_caption: Option<bool>,
+}
+
+impl TelegramRequest for CopyMessages {
+    type Response = Vec<MessageId>;
+
+    fn endpoint(&self) -> &str {
+        "copyMessages"
+    }
+}
+
+/// GetFile retrieves information about a file.
+///
+/// This will not download the file! It only returns a [File] containing
+/// the path which is needed to download the file. This returned ID lasts at
+/// least one hour.
+#[derive(Serialize, Debug, Clone)]
+pub struct GetFile {
+    /// The ID of the file to fetch.
+    pub file_id: String,
+}
+
+impl TelegramRequest for GetFile {
+    type Response = File;
+
+    fn endpoint(&self) -> &str {
+        "getFile"
+    }
+}
+
+#[derive(Debug, Serialize, Default, Clone)]
+pub struct SendMediaGroup {
+    pub chat_id: ChatID,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    #[serde(serialize_with = "clean_input_media")]
+    pub media: Vec<InputMedia>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i32>,
+}
+
+impl TelegramRequest for SendMediaGroup {
+    type Response = Vec<Message>;
+
+    fn endpoint(&self) -> &str {
+        "sendMediaGroup"
+    }
+
+    fn files(&self) -> RequestFiles {
+        if !self.media.iter().any(|item| item.get_file().needs_upload()) {
+            return None;
+        }
+
+        let mut items = Vec::new();
+
+        for item in &self.media {
+            let file = item.get_file();
+
+            let part = match file {
+                FileType::Bytes(file_name, bytes) => {
+                    let file = reqwest::multipart::Part::bytes(bytes.clone())
+                        .file_name(file_name.to_string());
+
+                    (file_name.to_string(), file)
+                }
+                _ => continue,
+            };
+
+            items.push(part);
+        }
+
+        Some(items)
+    }
+}
+
+/// Responds to an inline query request.
+#[derive(Debug, Serialize, Default, Clone)]
+pub struct AnswerInlineQuery {
+    /// ID of the inline query.
+    pub inline_query_id: String,
+    /// Results displayed to the user. May include up to 50 items.
+    pub results: Vec<InlineQueryResult>,
+    /// Maximum amount of time the server may cache these results for.
+    /// Defaults to 300.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_time: Option<i32>,
+    /// If the results are specific to the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_personal: Option<bool>,
+    /// Offset for getting more results, may be up to 64 bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<String>,
+    /// Provide a button with the specified text to start sending a message to
+    /// the bot, used with `switch_pm_parameter`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch_pm_text: Option<String>,
+    /// Deep-linking parameter when switching to PMs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch_pm_parameter: Option<String>,
+}
+
+impl TelegramRequest for AnswerInlineQuery {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "answerInlineQuery"
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct SetWebhook {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_updates: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_token: Option<String>,
+}
+
+impl TelegramRequest for SetWebhook {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "setWebhook"
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DeleteWebhook;
+
+impl TelegramRequest for DeleteWebhook {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "deleteWebhook"
+    }
+}
+
+/// Answers a callback query sent from an inline keyboard.
+///
+/// The answer is displayed to the user as a notification.
+#[derive(Clone, Default, Debug, Serialize)]
+pub struct AnswerCallbackQuery {
+    /// ID of the query to answer.
+    pub callback_query_id: String,
+    /// The text of the notification, may be 0-200 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// If an alert should be shown to the user. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_alert: Option<bool>,
+    /// URL opened by user's client.
+    ///
+    /// Must be a game URL if from a callback_game button, otherwise it may be
+    /// a link to open your bot with a parameter (`t.me/your_bot?start=XXXX`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Maximum time to cache the result.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_time: Option<i32>,
+}
+
+impl TelegramRequest for AnswerCallbackQuery {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "answerCallbackQuery"
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum MessageOrBool {
+    Message(Box<Message>),
+    Bool(bool),
+}
+
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct EditMessageText {
+    pub chat_id: ChatID,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_web_page_preview: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for EditMessageText {
+    type Response = MessageOrBool;
+
+    fn endpoint(&self) -> &str {
+        "editMessageText"
+    }
+}
+
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct EditMessageCaption {
+    pub chat_id: ChatID,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for EditMessageCaption {
+    type Response = MessageOrBool;
+
+    fn endpoint(&self) -> &str {
+        "editMessageCaption"
+    }
+}
+
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct EditMessageReplyMarkup {
+    pub chat_id: ChatID,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl TelegramRequest for EditMessageReplyMarkup {
+    type Response = MessageOrBool;
+
+    fn endpoint(&self) -> &str {
+        "editMessageReplyMarkup"
+    }
+}
+
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct DeleteMessage {
+    pub chat_id: ChatID,
+    pub message_id: i32,
+}
+
+impl TelegramRequest for DeleteMessage {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "deleteMessage"
+    }
+}
+
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct GetChat {
+    pub chat_id: ChatID,
+}
+
+impl TelegramRequest for GetChat {
+    type Response = Chat;
+
+    fn endpoint(&self) -> &str {
+        "getChat"
+    }
+}
+
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct GetChatAdministrators {
+    pub chat_id: ChatID,
+}
+
+impl TelegramRequest for GetChatAdministrators {
+    type Response = Vec<ChatMember>;
+
+    fn endpoint(&self) -> &str {
+        "getChatAdministrators"
+    }
+}
+
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct GetChatMember {
+    pub chat_id: ChatID,
+    pub user_id: i64,
+}
+
+impl TelegramRequest for GetChatMember {
+    type Response = ChatMember;
+
+    fn endpoint(&self) -> &str {
+        "getChatMember"
+    }
+}
+
+/// BanChatMember bans a user from a group, supergroup, or channel.
+///
+/// The bot must be an administrator with the appropriate rights.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct BanChatMember {
+    pub chat_id: ChatID,
+    pub user_id: i64,
+    /// Unix timestamp when the user will be unbanned. Bans for more than 366
+    /// days or less than 30 seconds are forever.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_date: Option<i64>,
+    /// If all messages from the user should be deleted. Always true for
+    /// supergroups and channels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revoke_messages: Option<bool>,
+}
+
+impl TelegramRequest for BanChatMember {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "banChatMember"
+    }
+}
+
+/// UnbanChatMember unbans a previously banned user in a supergroup or channel.
+///
+/// By default, this also removes users that are currently members.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct UnbanChatMember {
+    pub chat_id: ChatID,
+    pub user_id: i64,
+    /// If the user should only be unbanned if they are currently banned,
+    /// instead of being removed if they are a member.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_if_banned: Option<bool>,
+}
+
+impl TelegramRequest for UnbanChatMember {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "unbanChatMember"
+    }
+}
+
+/// RestrictChatMember changes the permissions of a user in a supergroup.
+///
+/// Permissions that are not set are restricted.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct RestrictChatMember {
+    pub chat_id: ChatID,
+    pub user_id: i64,
+    pub permissions: ChatPermissions,
+    /// If permissions should be applied independently instead of implying
+    /// related permissions, such as `can_send_polls` implying
+    /// `can_send_messages`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_independent_chat_permissions: Option<bool>,
+    /// Unix timestamp when the restrictions will be lifted. Restrictions for
+    /// more than 366 days or less than 30 seconds are forever.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_date: Option<i64>,
+}
+
+impl TelegramRequest for RestrictChatMember {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "restrictChatMember"
+    }
+}
+
+/// PromoteChatMember promotes or demotes a user in a supergroup or channel.
+///
+/// Setting every right to false demotes the user.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct PromoteChatMember {
+    pub chat_id: ChatID,
+    pub user_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_anonymous: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_chat: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_delete_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_video_chats: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_restrict_members: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_promote_members: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_change_info: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_invite_users: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_stories: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_stories: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_delete_stories: Option<bool>,
+    /// Channels only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_messages: Option<bool>,
+    /// Channels only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_messages: Option<bool>,
+    /// Supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+    /// Supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_topics: Option<bool>,
+}
+
+impl TelegramRequest for PromoteChatMember {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "promoteChatMember"
+    }
+}
+
+/// SetChatAdministratorCustomTitle sets the title of an administrator promoted
+/// by the bot in a supergroup.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct SetChatAdministratorCustomTitle {
+    pub chat_id: ChatID,
+    pub user_id: i64,
+    /// New custom title, may be 0-16 characters. Emoji are not allowed.
+    pub custom_title: String,
+}
+
+impl TelegramRequest for SetChatAdministratorCustomTitle {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "setChatAdministratorCustomTitle"
+    }
+}
+
+/// BanChatSenderChat bans a channel chat from sending messages as itself in a
+/// supergroup or channel.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct BanChatSenderChat {
+    pub chat_id: ChatID,
+    pub sender_chat_id: i64,
+}
+
+impl TelegramRequest for BanChatSenderChat {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "banChatSenderChat"
+    }
+}
+
+/// UnbanChatSenderChat unbans a previously banned channel chat.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct UnbanChatSenderChat {
+    pub chat_id: ChatID,
+    pub sender_chat_id: i64,
+}
+
+impl TelegramRequest for UnbanChatSenderChat {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "unbanChatSenderChat"
+    }
+}
+
+/// SetChatPermissions sets the default permissions for all members of a group
+/// or supergroup.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct SetChatPermissions {
+    pub chat_id: ChatID,
+    pub permissions: ChatPermissions,
+    /// If permissions should be applied independently instead of implying
+    /// related permissions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_independent_chat_permissions: Option<bool>,
+}
+
+impl TelegramRequest for SetChatPermissions {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "setChatPermissions"
+    }
+}
+
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct GetChatMemberCount {
+    pub chat_id: ChatID,
+}
+
+impl TelegramRequest for GetChatMemberCount {
+    type Response = i32;
+
+    fn endpoint(&self) -> &str {
+        "getChatMemberCount"
+    }
+}
+
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct LeaveChat {
+    pub chat_id: ChatID,
+}
+
+impl TelegramRequest for LeaveChat {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "leaveChat"
+    }
+}
+
+/// ExportChatInviteLink generates a new primary invite link for a chat,
+/// revoking the previous primary link.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct ExportChatInviteLink {
+    pub chat_id: ChatID,
+}
+
+impl TelegramRequest for ExportChatInviteLink {
+    type Response = String;
+
+    fn endpoint(&self) -> &str {
+        "exportChatInviteLink"
+    }
+}
+
+/// CreateChatInviteLink creates an additional invite link for a chat.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct CreateChatInviteLink {
+    pub chat_id: ChatID,
+    /// Name of the invite link, may be 0-32 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Unix timestamp when the link will expire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i64>,
+    /// Maximum number of users that may be members of the chat at the same
+    /// time after joining with this link, may be 1-99999.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<u32>,
+    /// If users joining with this link need to be approved by administrators.
+    /// May not be used with `member_limit`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creates_join_request: Option<bool>,
+}
+
+impl TelegramRequest for CreateChatInviteLink {
+    type Response = ChatInviteLink;
+
+    fn endpoint(&self) -> &str {
+        "createChatInviteLink"
+    }
+}
+
+/// EditChatInviteLink edits a non-primary invite link created by the bot.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct EditChatInviteLink {
+    pub chat_id: ChatID,
+    /// The invite link to edit.
+    pub invite_link: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creates_join_request: Option<bool>,
+}
+
+impl TelegramRequest for EditChatInviteLink {
+    type Response = ChatInviteLink;
+
+    fn endpoint(&self) -> &str {
+        "editChatInviteLink"
+    }
+}
+
+/// RevokeChatInviteLink revokes an invite link created by the bot.
+///
+/// If the primary link is revoked, a new one is generated.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct RevokeChatInviteLink {
+    pub chat_id: ChatID,
+    /// The invite link to revoke.
+    pub invite_link: String,
+}
+
+impl TelegramRequest for RevokeChatInviteLink {
+    type Response = ChatInviteLink;
+
+    fn endpoint(&self) -> &str {
+        "revokeChatInviteLink"
+    }
+}
+
+/// ApproveChatJoinRequest approves a [ChatJoinRequest].
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct ApproveChatJoinRequest {
+    pub chat_id: ChatID,
+    pub user_id: i64,
+}
+
+impl TelegramRequest for ApproveChatJoinRequest {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "approveChatJoinRequest"
+    }
+}
+
+/// DeclineChatJoinRequest declines a [ChatJoinRequest].
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct DeclineChatJoinRequest {
+    pub chat_id: ChatID,
+    pub user_id: i64,
+}
+
+impl TelegramRequest for DeclineChatJoinRequest {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "declineChatJoinRequest"
+    }
+}
+
+/// SetChatPhoto sets a new profile photo for a chat.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct SetChatPhoto {
+    pub chat_id: ChatID,
+    /// The new photo. It must be uploaded with [FileType::Bytes].
+    #[serde(skip_serializing_if = "FileType::needs_upload")]
+    pub photo: FileType,
+}
+
+impl TelegramRequest for SetChatPhoto {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "setChatPhoto"
+    }
+
+    fn files(&self) -> RequestFiles {
+        if self.photo.needs_upload() {
+            Some(vec![("photo".into(), self.photo.file().unwrap())])
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct DeleteChatPhoto {
+    pub chat_id: ChatID,
+}
+
+impl TelegramRequest for DeleteChatPhoto {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "deleteChatPhoto"
+    }
+}
+
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct SetChatTitle {
+    pub chat_id: ChatID,
+    /// New chat title, may be 1-128 characters.
+    pub title: String,
+}
+
+impl TelegramRequest for SetChatTitle {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "setChatTitle"
+    }
+}
+
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct SetChatDescription {
+    pub chat_id: ChatID,
+    /// New chat description, may be 0-255 characters. Removes the description
+    /// if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl TelegramRequest for SetChatDescription {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "setChatDescription"
+    }
+}
+
+/// PinChatMessage adds a message to the list of pinned messages in a chat.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct PinChatMessage {
+    pub chat_id: ChatID,
+    pub message_id: i32,
+    /// If members should not be notified about the pinned message.
+    /// Notifications are always disabled in channels and private chats.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+}
+
+impl TelegramRequest for PinChatMessage {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "pinChatMessage"
+    }
+}
+
+/// UnpinChatMessage removes a message from the list of pinned messages in a
+/// chat.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct UnpinChatMessage {
+    pub chat_id: ChatID,
+    /// The message to unpin. The most recently pinned message is unpinned if
+    /// not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i32>,
+}
+
+impl TelegramRequest for UnpinChatMessage {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "unpinChatMessage"
+    }
+}
+
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct UnpinAllChatMessages {
+    pub chat_id: ChatID,
+}
+
+impl TelegramRequest for UnpinAllChatMessages {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "unpinAllChatMessages"
+    }
+}
+
+/// SetChatStickerSet sets the group sticker set of a supergroup.
+///
+/// Check `can_set_sticker_set` on [Chat] to see if the bot can use this.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct SetChatStickerSet {
+    pub chat_id: ChatID,
+    pub sticker_set_name: String,
+}
+
+impl TelegramRequest for SetChatStickerSet {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "setChatStickerSet"
+    }
+}
+
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct DeleteChatStickerSet {
+    pub chat_id: ChatID,
+}
+
+impl TelegramRequest for DeleteChatStickerSet {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "deleteChatStickerSet"
+    }
+}
+
+/// The rights of an administrator in a chat.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChatAdministratorRights {
+    /// If the administrator's presence in the chat is hidden.
+    #[serde(default)]
+    pub is_anonymous: bool,
+    /// If the administrator can access the event log, boost list, member
+    /// list, and other administrator-only information. Implied by any other
+    /// administrator right.
+    #[serde(default)]
+    pub can_manage_chat: bool,
+    #[serde(default)]
+    pub can_delete_messages: bool,
+    #[serde(default)]
+    pub can_manage_video_chats: bool,
+    /// If the administrator can restrict, ban, or unban members, or access
+    /// supergroup statistics.
+    #[serde(default)]
+    pub can_restrict_members: bool,
+    /// If the administrator can add new administrators with a subset of their
+    /// own rights, or demote administrators they promoted.
+    #[serde(default)]
+    pub can_promote_members: bool,
+    /// If the administrator can change the chat title, photo, and other
+    /// settings.
+    #[serde(default)]
+    pub can_change_info: bool,
+    #[serde(default)]
+    pub can_invite_users: bool,
+    #[serde(default)]
+    pub can_post_stories: bool,
+    #[serde(default)]
+    pub can_edit_stories: bool,
+    #[serde(default)]
+    pub can_delete_stories: bool,
+    /// Channels only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_messages: Option<bool>,
+    /// Channels only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_messages: Option<bool>,
+    /// Groups and supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+    /// Supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_topics: Option<bool>,
+}
+
+/// SetMyDefaultAdministratorRights changes the rights requested by default
+/// when the bot is added as an administrator.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct SetMyDefaultAdministratorRights {
+    pub rights: ChatAdministratorRights,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub for_channels: Option<bool>,
+}
+
+impl TelegramRequest for SetMyDefaultAdministratorRights {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "setMyDefaultAdministratorRights"
+    }
+}
+
+/// GetMyDefaultAdministratorRights gets the rights requested by default when
+/// the bot is added as an administrator.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct GetMyDefaultAdministratorRights {
+    /// If the rights for channels should be returned instead of the rights
+    /// for groups and supergroups.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub for_channels: Option<bool>,
+}
+
+impl TelegramRequest for GetMyDefaultAdministratorRights {
+    type Response = ChatAdministratorRights;
+
+    fn endpoint(&self) -> &str {
+        "getMyDefaultAdministratorRights"
+    }
+}
+
+/// A command supported by the bot.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BotCommand {
+    /// Text of the command, may be 1-32 characters of lowercase English
+    /// letters, digits, and underscores. Does not include the leading `/`.
+    pub command: String,
+    /// Description of the command, may be 1-256 characters.
+    pub description: String,
+}
+
+/// The users for which a list of [BotCommands](BotCommand) applies.
+///
+/// When displaying commands, Telegram uses the most specific scope with
+/// commands set for the user's language, then falls back to the same scope
+/// without a language before trying the next scope.
+#[derive(Default, Debug, Serialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BotCommandScope {
+    /// Used if no commands were set for a narrower scope.
+    #[default]
+    Default,
+    /// All private chats.
+    AllPrivateChats,
+    /// All group and supergroup chats.
+    AllGroupChats,
+    /// All administrators of group and supergroup chats.
+    AllChatAdministrators,
+    /// A specific chat.
+    Chat { chat_id: ChatID },
+    /// All administrators of a specific group or supergroup chat.
+    ChatAdministrators { chat_id: ChatID },
+    /// A specific member of a group or supergroup chat.
+    ChatMember { chat_id: ChatID, user_id: i64 },
+}
+
+/// SetMyCommands changes the list of the bot's commands.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct SetMyCommands {
+    /// Commands for the bot, may include up to 100 items.
+    pub commands: Vec<BotCommand>,
+    /// Users the commands apply to. Defaults to [BotCommandScope::Default].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope>,
+    /// Two-letter ISO 639-1 language code. If not set, the commands apply to
+    /// all users in the scope without dedicated commands for their language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl TelegramRequest for SetMyCommands {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "setMyCommands"
+    }
+}
+
+/// GetMyCommands gets the list of the bot's commands for a scope and language.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct GetMyCommands {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl TelegramRequest for GetMyCommands {
+    type Response = Vec<BotCommand>;
+
+    fn endpoint(&self) -> &str {
+        "getMyCommands"
+    }
+}
+
+/// DeleteMyCommands deletes the list of the bot's commands for a scope and
+/// language, so commands from a broader scope are shown instead.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct DeleteMyCommands {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl TelegramRequest for DeleteMyCommands {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "deleteMyCommands"
+    }
+}
+
+/// SetMyName changes the bot's name.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct SetMyName {
+    /// New name of the bot, may be 0-64 characters. If empty, the name for
+    /// the language is removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Two-letter ISO 639-1 language code. If not set, the name is shown to
+    /// all users without a dedicated name for their language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl TelegramRequest for SetMyName {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "setMyName"
+    }
+}
+
+/// GetMyName gets the bot's name for a language.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct GetMyName {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl TelegramRequest for GetMyName {
+    type Response = BotName;
+
+    fn endpoint(&self) -> &str {
+        "getMyName"
+    }
+}
+
+/// SetMyDescription changes the bot's description.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct SetMyDescription {
+    /// New description of the bot, may be 0-512 characters. If empty, the
+    /// description for the language is removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Two-letter ISO 639-1 language code. If not set, the description is
+    /// shown to all users without a dedicated description for their language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl TelegramRequest for SetMyDescription {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "setMyDescription"
+    }
+}
+
+/// GetMyDescription gets the bot's description for a language.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct GetMyDescription {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl TelegramRequest for GetMyDescription {
+    type Response = BotDescription;
+
+    fn endpoint(&self) -> &str {
+        "getMyDescription"
+    }
+}
+
+/// SetMyShortDescription changes the bot's short description.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct SetMyShortDescription {
+    /// New short description of the bot, may be 0-120 characters. If empty,
+    /// the short description for the language is removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_description: Option<String>,
+    /// Two-letter ISO 639-1 language code. If not set, the short description
+    /// is shown to all users without a dedicated short description for their
+    /// language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl TelegramRequest for SetMyShortDescription {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "setMyShortDescription"
+    }
+}
+
+/// GetMyShortDescription gets the bot's short description for a language.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct GetMyShortDescription {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+}
+
+impl TelegramRequest for GetMyShortDescription {
+    type Response = BotShortDescription;
+
+    fn endpoint(&self) -> &str {
+        "getMyShortDescription"
+    }
+}
+
+/// SetChatMenuButton changes the bot's menu button in a private chat, or the
+/// default menu button.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct SetChatMenuButton {
+    /// The private chat to change the menu button in. If not set, the default
+    /// menu button is changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<i64>,
+    /// The new menu button. Defaults to [MenuButton::Default].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub menu_button: Option<MenuButton>,
+}
+
+impl TelegramRequest for SetChatMenuButton {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "setChatMenuButton"
+    }
+}
+
+/// GetChatMenuButton gets the bot's menu button in a private chat, or the
+/// default menu button.
+#[derive(Default, Debug, Serialize, Clone)]
+pub struct GetChatMenuButton {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<i64>,
+}
+
+impl TelegramRequest for GetChatMenuButton {
+    type Response = MenuButton;
+
+    fn endpoint(&self) -> &str {
+        "getChatMenuButton"
+    }
+}
+
+/// The format of a sticker.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum StickerFormat {
+    /// A WEBP or PNG image.
+    #[default]
+    Static,
+    /// A TGS animation.
+    Animated,
+    /// A WEBM video.
+    Video,
+}
+
+/// A sticker to add to a sticker set.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct InputSticker {
+    /// The sticker file. New files are uploaded as attachments.
+    pub sticker: FileType,
+    pub format: StickerFormat,
+    /// Emoji associated with the sticker, may include 1-20 items.
+    pub emoji_list: Vec<String>,
+    /// Position where the mask should be placed, for mask stickers only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+    /// Search keywords for the sticker, may include 0-20 items of up to 64
+    /// characters total. For regular and custom emoji stickers only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<Vec<String>>,
+}
+
+impl InputSticker {
+    /// Get a copy of this sticker with a file that needs to be uploaded
+    /// replaced by an attachment with the same name.
+    pub(crate) fn attached(&self) -> Self {
+        match &self.sticker {
+            FileType::Missing => panic!("tried to uploading missing file"),
+            FileType::Bytes(file_name, _) => Self {
+                sticker: FileType::Attach(format!("attach://{}", file_name)),
+                ..self.clone()
+            },
+            _ => self.clone(),
+        }
+    }
+
+    /// Get the multipart Part for the sticker, if it needs to be uploaded.
+    fn part(&self) -> Option<(String, reqwest::multipart::Part)> {
+        match &self.sticker {
+            FileType::Bytes(file_name, _) => Some((file_name.to_string(), self.sticker.file()?)),
+            _ => None,
+        }
+    }
+}
+
+/// GetStickerSet gets a sticker set by name.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct GetStickerSet {
+    pub name: String,
+}
+
+impl TelegramRequest for GetStickerSet {
+    type Response = StickerSet;
+
+    fn endpoint(&self) -> &str {
+        "getStickerSet"
+    }
+}
+
+/// UploadStickerFile uploads a sticker file to be used in multiple sticker
+/// set requests.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct UploadStickerFile {
+    /// The user who will own the created sticker set.
+    pub user_id: i64,
+    /// The sticker file. It must be uploaded with [FileType::Bytes].
+    #[serde(skip_serializing_if = "FileType::needs_upload")]
+    pub sticker: FileType,
+    pub sticker_format: StickerFormat,
+}
+
+impl TelegramRequest for UploadStickerFile {
+    type Response = File;
+
+    fn endpoint(&self) -> &str {
+        "uploadStickerFile"
+    }
+
+    fn files(&self) -> RequestFiles {
+        if self.sticker.needs_upload() {
+            Some(vec![("sticker".into(), self.sticker.file().unwrap())])
+        } else {
+            None
+        }
+    }
+}
+
+/// CreateNewStickerSet creates a new sticker set owned by a user.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct CreateNewStickerSet {
+    /// The user who will own the created sticker set.
+    pub user_id: i64,
+    /// Short name of the sticker set, used in `t.me/addstickers/` URLs. It
+    /// must end in `_by_<bot_username>`.
+    pub name: String,
+    /// Title of the sticker set, may be 1-64 characters.
+    pub title: String,
+    /// Initial stickers in the set, may include 1-50 items.
+    #[serde(serialize_with = "clean_input_stickers")]
+    pub stickers: Vec<InputSticker>,
+    /// Type of stickers in the set. Defaults to regular.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticker_type: Option<StickerType>,
+    /// If custom emoji in the set should be repainted to match the text color
+    /// where they are used. For custom emoji sets only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub needs_repainting: Option<bool>,
+}
+
+impl TelegramRequest for CreateNewStickerSet {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "createNewStickerSet"
+    }
+
+    fn files(&self) -> RequestFiles {
+        let items: Vec<_> = self
+            .stickers
+            .iter()
+            .filter_map(InputSticker::part)
+            .collect();
+
+        if items.is_empty() {
+            None
+        } else {
+            Some(items)
+        }
+    }
+}
+
+/// AddStickerToSet adds a sticker to a sticker set created by the bot.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct AddStickerToSet {
+    /// The user who owns the sticker set.
+    pub user_id: i64,
+    pub name: String,
+    #[serde(serialize_with = "clean_input_sticker")]
+    pub sticker: InputSticker,
+}
+
+impl TelegramRequest for AddStickerToSet {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "addStickerToSet"
+    }
+
+    fn files(&self) -> RequestFiles {
+        self.sticker.part().map(|part| vec![part])
+    }
+}
+
+/// SetStickerPositionInSet moves a sticker in a set created by the bot.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SetStickerPositionInSet {
+    /// File ID of the sticker.
+    pub sticker: String,
+    /// New 0-based position of the sticker in the set.
+    pub position: i32,
+}
+
+impl TelegramRequest for SetStickerPositionInSet {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "setStickerPositionInSet"
     }
 }
 
-/// GetUpdates is a request that returns any available [Updates](Update).
-#[derive(Serialize, Default, Debug, Clone)]
-pub struct GetUpdates {
-    /// ID for the first update to return. This must be set to one higher
-    /// than previous IDs in order to confirm previous updates and clear them.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub offset: Option<i32>,
-    /// Maximum number of [Updates](Update) to retrieve. May be set 1-100,
-    /// defaults to 100.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i32>,
-    /// Number of seconds for long polling. This should be set to a reasonable
-    /// value in production to avoid unneeded requests.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub timeout: Option<i32>,
-    /// Which update types to receive. May be set to any available types.
-    /// * `message`
-    /// * `edited_message`
-    /// * `channel_post`
-    /// * `edited_channel_post`
-    /// * `inline_query`
-    /// * `chosen_inline_result`
-    /// * `callback_query`
-    /// * `shipping_query`
-    /// * `pre_checkout_query`
-    /// * `poll`
-    /// * `poll_answer`
-    /// * `my_chat_member`
-    /// * `chat_member`
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_updates: Option<Vec<String>>,
+/// DeleteStickerFromSet deletes a sticker from a set created by the bot.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct DeleteStickerFromSet {
+    /// File ID of the sticker.
+    pub sticker: String,
 }
 
-impl TelegramRequest for GetUpdates {
-    type Response = Vec<Update>;
+impl TelegramRequest for DeleteStickerFromSet {
+    type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "getUpdates"
+        "deleteStickerFromSet"
     }
 }
 
-/// SendMessage sends a message.
-///
-/// # Example
+/// SetStickerSetThumbnail sets the thumbnail of a regular or mask sticker set.
 ///
-/// ```
-/// # use tgbotapi::requests::{ChatID, SendMessage};
-/// let send_message = SendMessage {
-///     chat_id: ChatID::Identifier(12345),
-///     text: "Hello, world!".into(),
-///     ..Default::default()
-/// };
-/// ```
-#[derive(Serialize, Default, Debug, Clone)]
-pub struct SendMessage {
-    /// The ID of the chat to send a message to.
-    pub chat_id: ChatID,
-    /// The text of the message. May be 1-4096 characters after entity parsing.
-    pub text: String,
-    /// The mode used to parse the provided text.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<ParseMode>,
-    /// If Telegram should not generate a web page preview.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub disable_web_page_preview: Option<bool>,
-    /// If the message should be sent silently (notification but no sound).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub disable_notification: Option<bool>,
-    /// The ID of the [Message] this Message is in reply to.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_to_message_id: Option<i32>,
-    /// Allow sending the message even if the reply message was not found.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub allow_sending_without_reply: Option<bool>,
-    /// The [ReplyMarkup], if desired.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<ReplyMarkup>,
+/// The format of the thumbnail must match the format of the stickers in the
+/// set.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SetStickerSetThumbnail {
+    pub name: String,
+    /// The user who owns the sticker set.
+    pub user_id: i64,
+    /// The new thumbnail. If not set, the thumbnail is removed and the first
+    /// sticker is used instead.
+    #[serde(skip_serializing_if = "SetStickerSetThumbnail::thumbnail_is_skipped")]
+    pub thumbnail: Option<FileType>,
+    pub format: StickerFormat,
+}
+
+impl SetStickerSetThumbnail {
+    fn thumbnail_is_skipped(thumbnail: &Option<FileType>) -> bool {
+        thumbnail
+            .as_ref()
+            .map(FileType::needs_upload)
+            .unwrap_or(true)
+    }
 }
 
-impl TelegramRequest for SendMessage {
-    type Response = Message;
+impl TelegramRequest for SetStickerSetThumbnail {
+    type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "sendMessage"
+        "setStickerSetThumbnail"
+    }
+
+    fn files(&self) -> RequestFiles {
+        match &self.thumbnail {
+            Some(thumbnail) if thumbnail.needs_upload() => {
+                Some(vec![("thumbnail".into(), thumbnail.file().unwrap())])
+            }
+            _ => None,
+        }
     }
 }
 
-/// SendChatAction allows you to indicate to users that the bot is performing
-/// an action.
-///
-/// Actions last for 5 seconds or until a message is sent,
-/// whichever comes first.
-#[derive(Serialize, Debug, Clone)]
-pub struct SendChatAction {
-    /// The ID of the chat to send an action to.
-    pub chat_id: ChatID,
-    /// The action to indicate.
-    pub action: ChatAction,
+/// SetStickerEmojiList changes the emoji of a sticker in a set created by the
+/// bot.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SetStickerEmojiList {
+    /// File ID of the sticker.
+    pub sticker: String,
+    /// Emoji associated with the sticker, may include 1-20 items.
+    pub emoji_list: Vec<String>,
 }
 
-impl TelegramRequest for SendChatAction {
+impl TelegramRequest for SetStickerEmojiList {
     type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "sendChatAction"
+        "setStickerEmojiList"
     }
 }
 
-/// SendPhoto sends a photo.
-#[derive(Serialize, Debug, Default, Clone)]
-pub struct SendPhoto {
-    /// The ID of the chat to send a photo to.
-    pub chat_id: ChatID,
-    /// The file that makes up this photo.
-    #[serde(skip_serializing_if = "FileType::needs_upload")]
-    pub photo: FileType,
-    /// A caption for the photo, if desired.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<String>,
-    /// If the photo should be covered with a spoiler animation.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_spoiler: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_to_message_id: Option<i32>,
-    /// Allow sending the message even if the reply message was not found.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub allow_sending_without_reply: Option<bool>,
+/// SetStickerKeywords changes the search keywords of a regular or custom emoji
+/// sticker in a set created by the bot.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SetStickerKeywords {
+    /// File ID of the sticker.
+    pub sticker: String,
+    /// Search keywords for the sticker, may include 0-20 items of up to 64
+    /// characters total. If not set, the keywords are removed.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<ReplyMarkup>,
+    pub keywords: Option<Vec<String>>,
 }
 
-impl TelegramRequest for SendPhoto {
-    type Response = Message;
+impl TelegramRequest for SetStickerKeywords {
+    type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "sendPhoto"
+        "setStickerKeywords"
     }
+}
 
-    fn files(&self) -> RequestFiles {
-        // Check if the photo needs to be uploaded. If the photo does need to
-        // be uploaded, we specify the field name and get the file. This unwrap
-        // is safe because `needs_upload` only returns true when it exists.
-        if self.photo.needs_upload() {
-            Some(vec![("photo".into(), self.photo.file().unwrap())])
-        } else {
-            None
-        }
+/// GetCustomEmojiStickers gets information about custom emoji stickers.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct GetCustomEmojiStickers {
+    /// Identifiers of the custom emoji, may include up to 200 items.
+    pub custom_emoji_ids: Vec<String>,
+}
+
+impl TelegramRequest for GetCustomEmojiStickers {
+    type Response = Vec<Sticker>;
+
+    fn endpoint(&self) -> &str {
+        "getCustomEmojiStickers"
     }
 }
 
-#[derive(Serialize, Debug, Default, Clone)]
-pub struct SendDocument {
-    /// The ID of the chat to send a photo to.
+/// SendInvoice sends an invoice for a payment.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SendInvoice {
     pub chat_id: ChatID,
-    /// The file that makes up this photo.
-    #[serde(skip_serializing_if = "FileType::needs_upload")]
-    pub document: FileType,
-    /// A caption for the photo, if desired.
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<String>,
+    pub message_thread_id: Option<i32>,
+    #[serde(flatten)]
+    pub invoice: InvoiceDetails,
+    /// Deep-linking parameter. If empty, forwarded copies of the invoice
+    /// can be paid by anyone. Otherwise they contain a button to start the
+    /// bot with this parameter.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<ParseMode>,
+    pub start_parameter: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_notification: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<ReplyMarkup>,
+    pub allow_sending_without_reply: Option<bool>,
+    /// If set, the first button must be a pay button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-impl TelegramRequest for SendDocument {
+impl TelegramRequest for SendInvoice {
     type Response = Message;
 
     fn endpoint(&self) -> &str {
-        "sendDocument"
+        "sendInvoice"
     }
+}
 
-    fn files(&self) -> RequestFiles {
-        if self.document.needs_upload() {
-            Some(vec![("document".into(), self.document.file().unwrap())])
-        } else {
-            None
-        }
+/// CreateInvoiceLink creates a link for an invoice.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct CreateInvoiceLink {
+    #[serde(flatten)]
+    pub invoice: InvoiceDetails,
+}
+
+impl TelegramRequest for CreateInvoiceLink {
+    type Response = String;
+
+    fn endpoint(&self) -> &str {
+        "createInvoiceLink"
     }
 }
 
-#[derive(Serialize, Debug, Default, Clone)]
-pub struct SendVideo {
-    pub chat_id: ChatID,
-    #[serde(skip_serializing_if = "FileType::needs_upload")]
-    pub video: FileType,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub duration: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub width: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub height: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<ParseMode>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_spoiler: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub supports_streaming: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub disable_notification: Option<bool>,
+/// A shipping option for an [AnswerShippingQuery] request.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ShippingOption {
+    pub id: String,
+    pub title: String,
+    pub prices: Vec<LabeledPrice>,
+}
+
+/// AnswerShippingQuery replies to a [ShippingQuery] with the available
+/// shipping options or an error.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct AnswerShippingQuery {
+    pub shipping_query_id: String,
+    /// If delivery to the address is possible.
+    pub ok: bool,
+    /// Available shipping options, required if ok is true.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_to_message_id: Option<i32>,
+    pub shipping_options: Option<Vec<ShippingOption>>,
+    /// Reason the order cannot be completed, required if ok is false.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<ReplyMarkup>,
+    pub error_message: Option<String>,
 }
 
-impl TelegramRequest for SendVideo {
-    type Response = Message;
+impl TelegramRequest for AnswerShippingQuery {
+    type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "sendVideo"
+        "answerShippingQuery"
     }
+}
 
-    fn files(&self) -> RequestFiles {
-        if self.video.needs_upload() {
-            Some(vec![("video".into(), self.video.file().unwrap())])
-        } else {
-            None
-        }
+/// AnswerPreCheckoutQuery confirms or rejects a [PreCheckoutQuery]. It must
+/// be sent within 10 seconds of receiving the query.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct AnswerPreCheckoutQuery {
+    pub pre_checkout_query_id: String,
+    /// If the bot is ready to deliver the goods or services.
+    pub ok: bool,
+    /// Reason the checkout cannot be completed, required if ok is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+impl TelegramRequest for AnswerPreCheckoutQuery {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "answerPreCheckoutQuery"
     }
 }
 
-#[derive(Serialize, Debug, Default, Clone)]
-pub struct SendAnimation {
-    pub chat_id: ChatID,
-    #[serde(skip_serializing_if = "FileType::needs_upload")]
-    pub animation: FileType,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub duration: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub width: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub height: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<ParseMode>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_spoiler: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub disable_notification: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_to_message_id: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<ReplyMarkup>,
+/// RefundStarPayment refunds a successful payment in Telegram Stars.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct RefundStarPayment {
+    pub user_id: i64,
+    /// Identifier from the [SuccessfulPayment].
+    pub telegram_payment_charge_id: String,
 }
 
-impl TelegramRequest for SendAnimation {
-    type Response = Message;
+impl TelegramRequest for RefundStarPayment {
+    type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "sendAnimation"
+        "refundStarPayment"
     }
+}
 
-    fn files(&self) -> RequestFiles {
-        if self.animation.needs_upload() {
-            Some(vec![("animation".into(), self.animation.file().unwrap())])
-        } else {
-            None
-        }
+/// GetStarTransactions gets the bot's Telegram Star transactions, newest
+/// first.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct GetStarTransactions {
+    /// Number of transactions to skip.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+    /// Maximum number of transactions to return, may be 1-100. Defaults to
+    /// 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+impl TelegramRequest for GetStarTransactions {
+    type Response = StarTransactions;
+
+    fn endpoint(&self) -> &str {
+        "getStarTransactions"
     }
 }
 
-/// GetFile retrieves information about a file.
+/// An error in Telegram Passport data that the user must fix.
 ///
-/// This will not download the file! It only returns a [File] containing
-/// the path which is needed to download the file. This returned ID lasts at
-/// least one hour.
+/// The user will not be able to share the element until the error is
+/// resolved.
 #[derive(Serialize, Debug, Clone)]
-pub struct GetFile {
-    /// The ID of the file to fetch.
-    pub file_id: String,
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum PassportElementError {
+    /// An issue in one of the data fields.
+    Data {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        field_name: String,
+        /// Base64-encoded data hash.
+        data_hash: String,
+        message: String,
+    },
+    /// An issue with the front side of a document.
+    FrontSide {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        /// Base64-encoded hash of the file.
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with the reverse side of a document.
+    ReverseSide {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        /// Base64-encoded hash of the file.
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with the selfie with a document.
+    Selfie {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        /// Base64-encoded hash of the file.
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with a document scan.
+    File {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        /// Base64-encoded hash of the file.
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with a list of document scans.
+    Files {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        /// Base64-encoded hashes of the files.
+        file_hashes: Vec<String>,
+        message: String,
+    },
+    /// An issue with one of the files that are a translation of a document.
+    TranslationFile {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        /// Base64-encoded hash of the file.
+        file_hash: String,
+        message: String,
+    },
+    /// An issue with the translation of a document.
+    TranslationFiles {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        /// Base64-encoded hashes of the files.
+        file_hashes: Vec<String>,
+        message: String,
+    },
+    /// An issue in an unspecified place.
+    Unspecified {
+        #[serde(rename = "type")]
+        element_type: EncryptedPassportElementType,
+        /// Base64-encoded element hash.
+        element_hash: String,
+        message: String,
+    },
+}
+
+/// SetPassportDataErrors informs a user that some of the Telegram Passport
+/// elements they provided contain errors.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SetPassportDataErrors {
+    pub user_id: i64,
+    pub errors: Vec<PassportElementError>,
 }
 
-impl TelegramRequest for GetFile {
-    type Response = File;
+impl TelegramRequest for SetPassportDataErrors {
+    type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "getFile"
+        "setPassportDataErrors"
     }
 }
 
-#[derive(Debug, Serialize, Default, Clone)]
-pub struct SendMediaGroup {
-    pub chat_id: ChatID,
-    #[serde(serialize_with = "clean_input_media")]
-    pub media: Vec<InputMedia>,
+/// SendGame sends a game.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SendGame {
+    pub chat_id: i64,
+    /// Unique identifier for the target message thread (topic) of the forum;
+    /// for forum supergroups only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i32>,
+    /// Short name of the game, set up with BotFather.
+    pub game_short_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_notification: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_sending_without_reply: Option<bool>,
+    /// If set, the first button must launch the game. If not set, a button
+    /// to play the game is added.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
-impl TelegramRequest for SendMediaGroup {
-    type Response = Vec<Message>;
+impl TelegramRequest for SendGame {
+    type Response = Message;
 
     fn endpoint(&self) -> &str {
-        "sendMediaGroup"
-    }
-
-    fn files(&self) -> RequestFiles {
-        if !self.media.iter().any(|item| item.get_file().needs_upload()) {
-            return None;
-        }
-
-        let mut items = Vec::new();
-
-        for item in &self.media {
-            let file = item.get_file();
-
-            let part = match file {
-                FileType::Bytes(file_name, bytes) => {
-                    let file = reqwest::multipart::Part::bytes(bytes.clone())
-                        .file_name(file_name.to_string());
-
-                    (file_name.to_string(), file)
-                }
-                _ => continue,
-            };
-
-            items.push(part);
-        }
-
-        Some(items)
+        "sendGame"
     }
 }
 
-/// Responds to an inline query request.
-#[derive(Debug, Serialize, Default, Clone)]
-pub struct AnswerInlineQuery {
-    /// ID of the inline query.
-    pub inline_query_id: String,
-    /// Results displayed to the user. May include up to 50 items.
-    pub results: Vec<InlineQueryResult>,
-    /// Maximum amount of time the server may cache these results for.
-    /// Defaults to 300.
+/// SetGameScore sets the score of a user in a game.
+///
+/// Either `chat_id` and `message_id` or `inline_message_id` must be set.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SetGameScore {
+    pub user_id: i64,
+    /// New score, must be non-negative.
+    pub score: i32,
+    /// If the score may be lower than the user's current score.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cache_time: Option<i32>,
-    /// If the results are specific to the user.
+    pub force: Option<bool>,
+    /// If the game message should not be edited to include the new score.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_personal: Option<bool>,
-    /// Offset for getting more results, may be up to 64 bytes.
+    pub disable_edit_message: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_offset: Option<String>,
-    /// Provide a button with the specified text to start sending a message to
-    /// the bot, used with `switch_pm_parameter`.
+    pub chat_id: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub switch_pm_text: Option<String>,
-    /// Deep-linking parameter when switching to PMs.
+    pub message_id: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub switch_pm_parameter: Option<String>,
+    pub inline_message_id: Option<String>,
 }
 
-impl TelegramRequest for AnswerInlineQuery {
-    type Response = bool;
+impl TelegramRequest for SetGameScore {
+    type Response = MessageOrBool;
 
     fn endpoint(&self) -> &str {
-        "answerInlineQuery"
+        "setGameScore"
     }
 }
 
-#[derive(Clone, Debug, Serialize, Default)]
-pub struct SetWebhook {
-    pub url: String,
+/// GetGameHighScores gets the high scores of a user and several of their
+/// neighbors in a game.
+///
+/// Either `chat_id` and `message_id` or `inline_message_id` must be set.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct GetGameHighScores {
+    pub user_id: i64,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_updates: Option<Vec<String>>,
+    pub chat_id: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub secret_token: Option<String>,
+    pub message_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
 }
 
-impl TelegramRequest for SetWebhook {
-    type Response = bool;
+impl TelegramRequest for GetGameHighScores {
+    type Response = Vec<GameHighScore>;
 
     fn endpoint(&self) -> &str {
-        "setWebhook"
+        "getGameHighScores"
     }
 }
 
-#[derive(Clone, Debug, Serialize)]
-pub struct DeleteWebhook;
+/// CreateForumTopic creates a topic in a forum supergroup.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct CreateForumTopic {
+    pub chat_id: ChatID,
+    /// Topic name, may be 1-128 characters.
+    pub name: String,
+    /// Color of the topic icon in RGB format. May be one of 0x6FB9F0,
+    /// 0xFFD67E, 0xCB86DB, 0x8EEE98, 0xFF93B2, or 0xFB6F5F.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_color: Option<i32>,
+    /// Custom emoji identifier of the topic icon, from
+    /// [GetForumTopicIconStickers].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_custom_emoji_id: Option<String>,
+}
 
-impl TelegramRequest for DeleteWebhook {
-    type Response = bool;
+impl TelegramRequest for CreateForumTopic {
+    type Response = ForumTopic;
 
     fn endpoint(&self) -> &str {
-        "deleteWebhook"
+        "createForumTopic"
     }
 }
 
-/// Answers a callback query sent from an inline keyboard.
-///
-/// The answer is displayed to the user as a notification.
-#[derive(Clone, Default, Debug, Serialize)]
-pub struct AnswerCallbackQuery {
-    /// ID of the query to answer.
-    pub callback_query_id: String,
-    /// The text of the notification, may be 0-200 characters.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub text: Option<String>,
-    /// If an alert should be shown to the user. Defaults to false.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub show_alert: Option<bool>,
-    /// URL opened by user's client.
-    ///
-    /// Must be a game URL if from a callback_game button, otherwise it may be
-    /// a link to open your bot with a parameter (`t.me/your_bot?start=XXXX`).
+/// EditForumTopic changes the name and icon of a forum topic.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct EditForumTopic {
+    pub chat_id: ChatID,
+    pub message_thread_id: i32,
+    /// New topic name, may be 0-128 characters. If not set, the name is not
+    /// changed.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
-    /// Maximum time to cache the result.
+    pub name: Option<String>,
+    /// New custom emoji identifier of the topic icon. An empty string removes
+    /// the icon, if not set the icon is not changed.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cache_time: Option<i32>,
+    pub icon_custom_emoji_id: Option<String>,
 }
 
-impl TelegramRequest for AnswerCallbackQuery {
+impl TelegramRequest for EditForumTopic {
     type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "answerCallbackQuery"
+        "editForumTopic"
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
-#[serde(untagged)]
-pub enum MessageOrBool {
-    Message(Box<Message>),
-    Bool(bool),
-}
-
-#[derive(Default, Debug, Serialize, Clone)]
-pub struct EditMessageText {
+/// CloseForumTopic closes an open forum topic.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct CloseForumTopic {
     pub chat_id: ChatID,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub inline_message_id: Option<String>,
-    pub text: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<ParseMode>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub entities: Option<Vec<MessageEntity>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub disable_web_page_preview: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<ReplyMarkup>,
+    pub message_thread_id: i32,
 }
 
-impl TelegramRequest for EditMessageText {
-    type Response = MessageOrBool;
+impl TelegramRequest for CloseForumTopic {
+    type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "editMessageText"
+        "closeForumTopic"
     }
 }
 
-#[derive(Default, Debug, Serialize, Clone)]
-pub struct EditMessageCaption {
+/// ReopenForumTopic reopens a closed forum topic.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ReopenForumTopic {
     pub chat_id: ChatID,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub inline_message_id: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption_entities: Option<Vec<MessageEntity>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<ReplyMarkup>,
+    pub message_thread_id: i32,
 }
 
-impl TelegramRequest for EditMessageCaption {
-    type Response = MessageOrBool;
+impl TelegramRequest for ReopenForumTopic {
+    type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "editMessageCaption"
+        "reopenForumTopic"
     }
 }
 
-#[derive(Default, Debug, Serialize, Clone)]
-pub struct EditMessageReplyMarkup {
+/// DeleteForumTopic deletes a forum topic along with all of its messages.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct DeleteForumTopic {
     pub chat_id: ChatID,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub inline_message_id: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<ReplyMarkup>,
+    pub message_thread_id: i32,
 }
 
-impl TelegramRequest for EditMessageReplyMarkup {
-    type Response = MessageOrBool;
+impl TelegramRequest for DeleteForumTopic {
+    type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "editMessageReplyMarkup"
+        "deleteForumTopic"
     }
 }
 
-#[derive(Default, Debug, Serialize, Clone)]
-pub struct DeleteMessage {
+/// UnpinAllForumTopicMessages unpins all messages in a forum topic.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct UnpinAllForumTopicMessages {
     pub chat_id: ChatID,
-    pub message_id: i32,
+    pub message_thread_id: i32,
 }
 
-impl TelegramRequest for DeleteMessage {
+impl TelegramRequest for UnpinAllForumTopicMessages {
     type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "deleteMessage"
+        "unpinAllForumTopicMessages"
     }
 }
 
-#[derive(Default, Debug, Serialize, Clone)]
-pub struct GetChat {
+/// EditGeneralForumTopic changes the name of the General forum topic.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct EditGeneralForumTopic {
     pub chat_id: ChatID,
+    /// New topic name, may be 1-128 characters.
+    pub name: String,
 }
 
-impl TelegramRequest for GetChat {
-    type Response = Chat;
+impl TelegramRequest for EditGeneralForumTopic {
+    type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "getChat"
+        "editGeneralForumTopic"
     }
 }
 
-#[derive(Default, Debug, Serialize, Clone)]
-pub struct GetChatAdministrators {
+/// CloseGeneralForumTopic closes the open General forum topic.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct CloseGeneralForumTopic {
     pub chat_id: ChatID,
 }
 
-impl TelegramRequest for GetChatAdministrators {
-    type Response = Vec<ChatMember>;
+impl TelegramRequest for CloseGeneralForumTopic {
+    type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "getChatAdministrators"
+        "closeGeneralForumTopic"
     }
 }
 
-#[derive(Default, Debug, Serialize, Clone)]
-pub struct GetChatMember {
+/// ReopenGeneralForumTopic reopens the closed General forum topic. It is
+/// unhidden if it was hidden.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ReopenGeneralForumTopic {
     pub chat_id: ChatID,
-    pub user_id: i64,
 }
 
-impl TelegramRequest for GetChatMember {
-    type Response = ChatMember;
+impl TelegramRequest for ReopenGeneralForumTopic {
+    type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "getChatMember"
+        "reopenGeneralForumTopic"
     }
 }
 
-#[derive(Default, Debug, Serialize, Clone)]
-pub struct ChatAdministratorRights {
-    pub is_anonymous: bool,
+/// HideGeneralForumTopic hides the General forum topic. It is closed if it
+/// was open.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct HideGeneralForumTopic {
+    pub chat_id: ChatID,
 }
 
-#[derive(Default, Debug, Serialize, Clone)]
-pub struct SetMyDefaultAdministratorRights {
-    #[serde(flatten)]
-    pub rights: ChatAdministratorRights,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub for_channels: Option<bool>,
+impl TelegramRequest for HideGeneralForumTopic {
+    type Response = bool;
+
+    fn endpoint(&self) -> &str {
+        "hideGeneralForumTopic"
+    }
 }
 
-impl TelegramRequest for SetMyDefaultAdministratorRights {
+/// UnhideGeneralForumTopic unhides the General forum topic.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct UnhideGeneralForumTopic {
+    pub chat_id: ChatID,
+}
+
+impl TelegramRequest for UnhideGeneralForumTopic {
     type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "setMyDefaultAdministratorRights"
+        "unhideGeneralForumTopic"
     }
 }
 
-#[derive(Default, Debug, Serialize, Clone)]
-pub struct GetMyDefaultAdministratorRights {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub for_channels: Option<bool>,
+/// UnpinAllGeneralForumTopicMessages unpins all messages in the General
+/// forum topic.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct UnpinAllGeneralForumTopicMessages {
+    pub chat_id: ChatID,
 }
 
-impl TelegramRequest for GetMyDefaultAdministratorRights {
+impl TelegramRequest for UnpinAllGeneralForumTopicMessages {
     type Response = bool;
 
     fn endpoint(&self) -> &str {
-        "getMyDefaultAdministratorRights"
+        "unpinAllGeneralForumTopicMessages"
+    }
+}
+
+/// GetForumTopicIconStickers gets the custom emoji stickers that may be used
+/// as forum topic icons.
+#[derive(Serialize, Debug, Clone)]
+pub struct GetForumTopicIconStickers;
+
+impl TelegramRequest for GetForumTopicIconStickers {
+    type Response = Vec<Sticker>;
+
+    fn endpoint(&self) -> &str {
+        "getForumTopicIconStickers"
     }
 }
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use crate::{Error, TelegramErrorKind};
+
+/// How [Telegram](crate::Telegram) retries requests that failed.
+///
+/// Requests are retried when Telegram responds with a `retry_after` because of
+/// flood control, or when a connection could not be established. Other errors
+/// are returned immediately because the request may have already been
+/// processed by Telegram.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the initial request.
+    pub max_attempts: u32,
+    /// Delay before retrying after the first network failure. This doubles
+    /// with each attempt and has jitter applied.
+    pub initial_delay: Duration,
+    /// Longest time to wait before a retry. If Telegram asks to wait longer
+    /// than this, the error is returned instead.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Determine how long to wait before retrying a request after it failed
+    /// with the given error, or `None` if it should not be retried.
+    ///
+    /// Attempts start at 1 for the initial request.
+    pub fn delay(&self, attempt: u32, err: &Error) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        match err {
+            Error::Telegram(err) => match err.kind() {
+                TelegramErrorKind::TooManyRequests {
+                    retry_after: Some(retry_after),
+                } => {
+                    let delay = Duration::from_secs(retry_after.max(0) as u64);
+
+                    (delay <= self.max_delay).then_some(delay)
+                }
+                _ => None,
+            },
+            Error::Request(err) if err.is_connect() => {
+                let exponent = attempt.saturating_sub(1).min(31);
+                let delay = self
+                    .initial_delay
+                    .saturating_mul(1 << exponent)
+                    .min(self.max_delay);
+
+                // Wait somewhere between half and all of the delay so many
+                // failed requests do not retry at the same time.
+                let half = delay / 2;
+                Some(half + half.mul_f64(fastrand::f64()))
+            }
+            _ => None,
+        }
+    }
+}
@@ -47,6 +47,9 @@ pub struct Update {
     pub poll_answer: Option<PollAnswer>,
     pub my_chat_member: Option<ChatMemberUpdated>,
     pub chat_member: Option<ChatMemberUpdated>,
+    pub chat_join_request: Option<ChatJoinRequest>,
+    pub shipping_query: Option<ShippingQuery>,
+    pub pre_checkout_query: Option<PreCheckoutQuery>,
 }
 
 #[derive(Clone, Debug, Deserialize, Default, PartialEq, Serialize)]
@@ -94,18 +97,45 @@ pub struct Chat {
     pub sticker_set_name: Option<String>,
     pub can_set_sticker_set: Option<bool>,
     pub linked_chat_id: Option<i64>,
+    /// If the supergroup chat is a forum with topics enabled.
+    pub is_forum: Option<bool>,
 }
 
+/// Actions that non-administrator users are allowed to take in a chat.
 #[derive(Clone, Debug, Deserialize, Default, Serialize)]
 pub struct ChatPermissions {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_messages: Option<bool>,
+    /// Replaced by the individual media permissions, only used by older
+    /// versions of the Bot API.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_media_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_audios: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_documents: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_photos: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_videos: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_video_notes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_voice_notes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_polls: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_other_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_add_web_page_previews: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_change_info: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_invite_users: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_pin_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_topics: Option<bool>,
 }
 
 /// An entity within a message's text or caption.
@@ -155,12 +185,16 @@ pub enum MessageEntityType {
 pub struct Message {
     /// Unique identifier for this message in this chat.
     pub message_id: i32,
+    /// Identifier of the forum topic the message belongs to.
+    pub message_thread_id: Option<i32>,
     /// Sender of message. Will be empty in channels.
     pub from: Option<User>,
     /// Date the message was sent, as a unix timestasmp.
     pub date: i64,
     /// Chat the message was sent to.
     pub chat: Chat,
+    /// If the message was sent to a forum topic.
+    pub is_topic_message: Option<bool>,
     /// Original sender of a message, if it was forwarded.
     pub forward_from: Option<User>,
     /// Original chat of a message, if it was forwarded from a channel.
@@ -187,6 +221,8 @@ pub struct Message {
     pub text: Option<String>,
     /// Entities contained within the text.
     pub entities: Option<Vec<MessageEntity>>,
+    /// Options used for the link preview of a text message.
+    pub link_preview_options: Option<LinkPreviewOptions>,
     /// Entities contained within the caption.
     pub caption_entities: Option<Vec<MessageEntity>>,
     /// If the message was audio, the audio information.
@@ -222,6 +258,8 @@ pub struct Message {
     pub venue: Option<Venue>,
     /// If the message was a poll, the poll information.
     pub poll: Option<Poll>,
+    /// If the message was a dice, the dice information.
+    pub dice: Option<Dice>,
     /// If new members were added, information about those users.
     ///
     /// This includes the bot when it is added to a group.
@@ -248,16 +286,44 @@ pub struct Message {
     pub migrate_from_chat_id: Option<i64>,
     /// If a message was pinned, the pinned message.
     pub pinned_message: Option<Box<Message>>,
-    // TODO: this is missing invoice, successful_payment
+    /// If a forum topic was created, information about the topic.
+    pub forum_topic_created: Option<ForumTopicCreated>,
+    /// If a forum topic was edited, the changes to the topic.
+    pub forum_topic_edited: Option<ForumTopicEdited>,
+    /// If a forum topic was closed.
+    pub forum_topic_closed: Option<ForumTopicClosed>,
+    /// If a forum topic was reopened.
+    pub forum_topic_reopened: Option<ForumTopicReopened>,
+    /// If the General forum topic was hidden.
+    pub general_forum_topic_hidden: Option<GeneralForumTopicHidden>,
+    /// If the General forum topic was unhidden.
+    pub general_forum_topic_unhidden: Option<GeneralForumTopicUnhidden>,
+    /// If the message is an invoice for a payment, information about the
+    /// invoice.
+    pub invoice: Option<Invoice>,
+    /// If the message is about a successful payment, information about the
+    /// payment.
+    pub successful_payment: Option<SuccessfulPayment>,
+    /// If the message is about a refunded payment, information about the
+    /// payment.
+    pub refunded_payment: Option<RefundedPayment>,
     /// If the user logged in, the domain name of the website.
     pub connected_website: Option<String>,
-    // TODO: this is missing passport_data
+    /// If the message contains Telegram Passport data, the encrypted data.
+    pub passport_data: Option<PassportData>,
     /// If the message had an inline keyboard, that inline keyboard data.
     ///
     /// `login_url` buttons will be represented as regular `url` buttons.
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
+/// A unique message identifier.
+#[derive(Clone, Debug, Deserialize, Default, PartialEq, Serialize)]
+pub struct MessageId {
+    /// Unique identifier for the message in its chat.
+    pub message_id: i32,
+}
+
 #[derive(Clone, Debug, Serialize)]
 /// Command represents information obtained from the BotCommand MessageEntity.
 pub struct Command {
@@ -350,7 +416,7 @@ pub struct ChosenInlineResult {
     pub query: String,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct File {
     /// The ID for this file, specific to this bot.
     pub file_id: String,
@@ -361,6 +427,12 @@ pub struct File {
     pub file_path: Option<String>,
 }
 
+/// A button on an [InlineKeyboardMarkup].
+///
+/// Exactly one of the optional fields must be set. The constructors such as
+/// [InlineKeyboardButton::callback] create buttons with a single action, and
+/// [InlineKeyboardBuilder::build] checks every button with
+/// [InlineKeyboardButton::validate].
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct InlineKeyboardButton {
     pub text: String,
@@ -371,9 +443,140 @@ pub struct InlineKeyboardButton {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub callback_data: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_app: Option<WebAppInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub switch_inline_query: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub switch_inline_query_current_chat: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_game: Option<CallbackGame>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pay: Option<bool>,
+}
+
+/// Maximum length of callback data, in bytes.
+pub const MAX_CALLBACK_DATA_LENGTH: usize = 64;
+
+impl InlineKeyboardButton {
+    /// Check that exactly one action is set on the button and that callback
+    /// data is 1-64 bytes.
+    pub fn validate(&self) -> Result<(), Error> {
+        let actions = [
+            self.url.is_some(),
+            self.login_url.is_some(),
+            self.callback_data.is_some(),
+            self.web_app.is_some(),
+            self.switch_inline_query.is_some(),
+            self.switch_inline_query_current_chat.is_some(),
+            self.callback_game.is_some(),
+            self.pay.unwrap_or(false),
+        ]
+        .into_iter()
+        .filter(|action| *action)
+        .count();
+
+        if actions != 1 {
+            return Err(Error::InlineKeyboardButtonActions(actions));
+        }
+
+        match &self.callback_data {
+            Some(data) if data.is_empty() || data.len() > MAX_CALLBACK_DATA_LENGTH => {
+                Err(Error::CallbackDataLength(data.len()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Create a button that sends a [CallbackQuery] with the given data.
+    ///
+    /// The data must be 1-64 bytes.
+    pub fn callback<T, D>(text: T, data: D) -> Result<Self, Error>
+    where
+        T: Into<String>,
+        D: Into<String>,
+    {
+        let data = data.into();
+
+        if data.is_empty() || data.len() > MAX_CALLBACK_DATA_LENGTH {
+            return Err(Error::CallbackDataLength(data.len()));
+        }
+
+        Ok(Self {
+            text: text.into(),
+            callback_data: Some(data),
+            ..Default::default()
+        })
+    }
+
+    /// Create a button that opens a URL.
+    pub fn url<T: Into<String>, U: Into<String>>(text: T, url: U) -> Self {
+        Self {
+            text: text.into(),
+            url: Some(url.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a button that authorizes the user with a [LoginUrl].
+    pub fn login_url<T: Into<String>>(text: T, login_url: LoginUrl) -> Self {
+        Self {
+            text: text.into(),
+            login_url: Some(login_url),
+            ..Default::default()
+        }
+    }
+
+    /// Create a button that opens a Web App. Only available in private chats.
+    pub fn web_app<T: Into<String>>(text: T, web_app: WebAppInfo) -> Self {
+        Self {
+            text: text.into(),
+            web_app: Some(web_app),
+            ..Default::default()
+        }
+    }
+
+    /// Create a button that prompts the user to select a chat, then inserts
+    /// the bot's username and the query into the input field.
+    pub fn switch_inline_query<T: Into<String>, Q: Into<String>>(text: T, query: Q) -> Self {
+        Self {
+            text: text.into(),
+            switch_inline_query: Some(query.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a button that inserts the bot's username and the query into the
+    /// input field of the current chat.
+    pub fn switch_inline_query_current_chat<T: Into<String>, Q: Into<String>>(
+        text: T,
+        query: Q,
+    ) -> Self {
+        Self {
+            text: text.into(),
+            switch_inline_query_current_chat: Some(query.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a button that launches the game of a message. It must be the
+    /// first button in the first row.
+    pub fn game<T: Into<String>>(text: T) -> Self {
+        Self {
+            text: text.into(),
+            callback_game: Some(CallbackGame {}),
+            ..Default::default()
+        }
+    }
+
+    /// Create a pay button. It must be the first button in the first row of
+    /// an invoice message.
+    pub fn pay<T: Into<String>>(text: T) -> Self {
+        Self {
+            text: text.into(),
+            pay: Some(true),
+            ..Default::default()
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
@@ -387,38 +590,172 @@ pub struct LoginUrl {
     pub request_write_access: Option<bool>,
 }
 
+/// Information about a Web App.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct WebAppInfo {
+    /// HTTPS URL of the Web App.
+    pub url: String,
+}
+
+/// The bot's menu button in a private chat.
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MenuButton {
+    /// Opens the bot's list of commands.
+    Commands,
+    /// Launches a Web App.
+    WebApp {
+        /// Text on the button.
+        text: String,
+        web_app: WebAppInfo,
+    },
+    /// No specific value for the menu button.
+    #[default]
+    Default,
+}
+
+/// The bot's name.
+#[derive(Default, Debug, Deserialize, Clone, PartialEq)]
+pub struct BotName {
+    pub name: String,
+}
+
+/// The bot's description, shown in the chat with the bot if it is empty.
+#[derive(Default, Debug, Deserialize, Clone, PartialEq)]
+pub struct BotDescription {
+    pub description: String,
+}
+
+/// The bot's short description, shown on the bot's profile page and sent
+/// with links to the bot.
+#[derive(Default, Debug, Deserialize, Clone, PartialEq)]
+pub struct BotShortDescription {
+    pub short_description: String,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InlineKeyboardMarkup {
     pub inline_keyboard: Vec<Vec<InlineKeyboardButton>>,
 }
 
+impl InlineKeyboardMarkup {
+    /// Create a builder for an [InlineKeyboardMarkup].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tgbotapi::{InlineKeyboardButton, InlineKeyboardMarkup};
+    /// # fn main() -> Result<(), tgbotapi::Error> {
+    /// let markup = InlineKeyboardMarkup::builder()
+    ///     .row([
+    ///         InlineKeyboardButton::callback("Yes", "vote:yes")?,
+    ///         InlineKeyboardButton::callback("No", "vote:no")?,
+    ///     ])
+    ///     .row([])
+    ///     .button(InlineKeyboardButton::url("Results", "https://example.com"))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> InlineKeyboardBuilder {
+        Default::default()
+    }
+}
+
+/// A builder for an [InlineKeyboardMarkup], created with
+/// [InlineKeyboardMarkup::builder].
+#[derive(Debug, Default, Clone)]
+pub struct InlineKeyboardBuilder {
+    rows: Vec<Vec<InlineKeyboardButton>>,
+}
+
+impl InlineKeyboardBuilder {
+    /// Add a new row containing the buttons.
+    pub fn row<I>(mut self, buttons: I) -> Self
+    where
+        I: IntoIterator<Item = InlineKeyboardButton>,
+    {
+        self.rows.push(buttons.into_iter().collect());
+        self
+    }
+
+    /// Add a button to the end of the last row, creating a row if there are
+    /// none.
+    pub fn button(mut self, button: InlineKeyboardButton) -> Self {
+        match self.rows.last_mut() {
+            Some(row) => row.push(button),
+            None => self.rows.push(vec![button]),
+        }
+
+        self
+    }
+
+    /// Build the [InlineKeyboardMarkup], skipping any empty rows.
+    ///
+    /// Returns an error if any button is not valid according to
+    /// [InlineKeyboardButton::validate].
+    pub fn build(self) -> Result<InlineKeyboardMarkup, Error> {
+        for button in self.rows.iter().flatten() {
+            button.validate()?;
+        }
+
+        Ok(InlineKeyboardMarkup {
+            inline_keyboard: self
+                .rows
+                .into_iter()
+                .filter(|row| !row.is_empty())
+                .collect(),
+        })
+    }
+}
+
 #[derive(Deserialize, Debug, Clone, Default, Serialize)]
 pub struct ChatInviteLink {
     pub invite_link: String,
     pub creator: User,
+    /// If users joining with this link need to be approved by administrators.
+    #[serde(default)]
+    pub creates_join_request: bool,
     pub is_primary: bool,
     pub is_revoked: bool,
+    /// Name of the invite link.
+    pub name: Option<String>,
     pub expire_date: Option<i32>,
     pub member_limit: Option<u32>,
+    /// Number of pending join requests created with this link.
+    pub pending_join_request_count: Option<u32>,
+}
+
+/// A request to join a chat.
+#[derive(Deserialize, Debug, Clone, Default, Serialize)]
+pub struct ChatJoinRequest {
+    /// Chat the request was sent to.
+    pub chat: Chat,
+    /// User that sent the request.
+    pub from: User,
+    /// Identifier of a private chat with the user, which may be used to
+    /// message them for 5 minutes or until the request is processed.
+    pub user_chat_id: i64,
+    /// Date the request was sent, as a unix timestamp.
+    pub date: i64,
+    /// Bio of the user.
+    pub bio: Option<String>,
+    /// Invite link that was used to send the request.
+    pub invite_link: Option<ChatInviteLink>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum ChatMemberStatus {
     Creator,
     Administrator,
+    #[default]
     Member,
     Restricted,
     Left,
     Kicked,
 }
 
-impl Default for ChatMemberStatus {
-    fn default() -> Self {
-        Self::Member
-    }
-}
-
 impl ChatMemberStatus {
     pub fn is_admin(&self) -> bool {
         *self == Self::Creator || *self == Self::Administrator
@@ -461,6 +798,7 @@ pub struct ChatMemberUpdated {
 /// The part of the face where the mask should be placed as a part of a mask
 /// position in a sticker.
 #[derive(Clone, Default, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum MaskPositionPoint {
     #[default]
     Forehead,
@@ -482,6 +820,32 @@ pub struct MaskPosition {
     pub scale: f64,
 }
 
+/// The type of stickers in a sticker set.
+#[derive(Clone, Default, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StickerType {
+    #[default]
+    Regular,
+    Mask,
+    CustomEmoji,
+    /// A sticker type not supported by this library.
+    #[serde(other)]
+    Other,
+}
+
+/// A sticker set.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StickerSet {
+    /// Name of the sticker set, used in `t.me/addstickers/` URLs.
+    pub name: String,
+    pub title: String,
+    /// Type of stickers in the set.
+    pub sticker_type: StickerType,
+    pub stickers: Vec<Sticker>,
+    /// Thumbnail for the sticker set, in WEBP, TGS, or WEBM format.
+    pub thumbnail: Option<PhotoSize>,
+}
+
 /// Information about a sticker.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Sticker {
@@ -490,20 +854,38 @@ pub struct Sticker {
     /// Unique identifier for this file which is reused between bots.
     /// May **not** be used to download or reuse the file.
     pub file_unique_id: String,
+    /// Type of the sticker, independent of its format.
+    #[serde(rename = "type", default)]
+    pub sticker_type: StickerType,
     /// Width of the sticker.
     pub width: i32,
     /// Height of the sticker.
     pub height: i32,
-    /// If the sticker is animated.
+    /// If the sticker is animated, in TGS format.
     pub is_animated: bool,
+    /// If the sticker is a video, in WEBM format.
+    #[serde(default)]
+    pub is_video: bool,
     /// Thumbnail for the sticker, may be in webp or jpg format.
+    pub thumbnail: Option<PhotoSize>,
+    /// Thumbnail for the sticker under its old name. The Bot API sends this
+    /// alongside `thumbnail`, older servers only send this.
+    #[deprecated(note = "use `thumbnail` instead")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub thumb: Option<PhotoSize>,
     /// Emoji associated with the sticker.
     pub emoji: Option<String>,
     /// Name of the associated sticker set.
     pub set_name: Option<String>,
+    /// For premium regular stickers, the premium animation.
+    pub premium_animation: Option<File>,
     /// For mask stickers, where the mask should be placed.
     pub mask_position: Option<MaskPosition>,
+    /// For custom emoji stickers, the unique identifier of the custom emoji.
+    pub custom_emoji_id: Option<String>,
+    /// For custom emoji stickers, if the sticker should be repainted to match
+    /// the text color where it is used.
+    pub needs_repainting: Option<bool>,
     /// File size of the sticker.
     pub file_size: Option<i64>,
 }
@@ -543,6 +925,20 @@ pub struct Animation {
     pub file_size: Option<i64>,
 }
 
+/// A placeholder for a button that launches a game. It currently holds no
+/// information.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CallbackGame {}
+
+/// A row of a game's high scores table.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GameHighScore {
+    /// Position in the high score table.
+    pub position: i32,
+    pub user: User,
+    pub score: i32,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Game {
     pub title: String,
@@ -608,6 +1004,33 @@ pub struct Venue {
     pub forsquare_type: Option<String>,
 }
 
+/// Options for how a link preview is generated for a message.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LinkPreviewOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_disabled: Option<bool>,
+    /// URL to use for the preview. If empty, the first URL in the text is
+    /// used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefer_small_media: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefer_large_media: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_above_text: Option<bool>,
+}
+
+/// An animated emoji that displays a random value.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Dice {
+    /// Emoji the dice animation is based on.
+    pub emoji: String,
+    /// Value of the dice. 1-6 for 🎲, 🎯, and 🎳, 1-5 for 🏀 and ⚽, and
+    /// 1-64 for 🎰.
+    pub value: i32,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Poll {
     pub id: String,
@@ -642,3 +1065,304 @@ pub struct PollAnswer {
     pub user: User,
     pub option_ids: Vec<i32>,
 }
+
+/// Basic information about an invoice.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Invoice {
+    pub title: String,
+    pub description: String,
+    /// Unique parameter used to generate this invoice with `/start`.
+    pub start_parameter: String,
+    /// Three-letter ISO 4217 currency code.
+    pub currency: String,
+    /// Total price in the smallest units of the currency.
+    pub total_amount: i32,
+}
+
+/// A shipping address provided by the user.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ShippingAddress {
+    /// Two-letter ISO 3166-1 alpha-2 country code.
+    pub country_code: String,
+    pub state: String,
+    pub city: String,
+    pub street_line1: String,
+    pub street_line2: String,
+    pub post_code: String,
+}
+
+/// Information about an order, as requested by the invoice.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OrderInfo {
+    pub name: Option<String>,
+    pub phone_number: Option<String>,
+    pub email: Option<String>,
+    pub shipping_address: Option<ShippingAddress>,
+}
+
+/// Basic information about a successful payment.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SuccessfulPayment {
+    /// Three-letter ISO 4217 currency code.
+    pub currency: String,
+    /// Total price in the smallest units of the currency.
+    pub total_amount: i32,
+    /// Bot-defined payload from the invoice.
+    pub invoice_payload: String,
+    /// Identifier of the shipping option chosen by the user.
+    pub shipping_option_id: Option<String>,
+    pub order_info: Option<OrderInfo>,
+    pub telegram_payment_charge_id: String,
+    pub provider_payment_charge_id: String,
+}
+
+/// A shipping query for an invoice with a flexible price.
+///
+/// It must be answered with
+/// [AnswerShippingQuery](crate::requests::AnswerShippingQuery).
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ShippingQuery {
+    pub id: String,
+    pub from: User,
+    /// Bot-defined payload from the invoice.
+    pub invoice_payload: String,
+    pub shipping_address: ShippingAddress,
+}
+
+/// A query sent before a payment is completed.
+///
+/// It must be answered with
+/// [AnswerPreCheckoutQuery](crate::requests::AnswerPreCheckoutQuery) within
+/// 10 seconds.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PreCheckoutQuery {
+    pub id: String,
+    pub from: User,
+    /// Three-letter ISO 4217 currency code.
+    pub currency: String,
+    /// Total price in the smallest units of the currency.
+    pub total_amount: i32,
+    /// Bot-defined payload from the invoice.
+    pub invoice_payload: String,
+    /// Identifier of the shipping option chosen by the user.
+    pub shipping_option_id: Option<String>,
+    pub order_info: Option<OrderInfo>,
+}
+
+/// Basic information about a refunded payment.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RefundedPayment {
+    /// Three-letter ISO 4217 currency code, currently always `XTR`.
+    pub currency: String,
+    /// Total refunded price in the smallest units of the currency.
+    pub total_amount: i32,
+    /// Bot-defined payload from the invoice.
+    pub invoice_payload: String,
+    pub telegram_payment_charge_id: String,
+    pub provider_payment_charge_id: Option<String>,
+}
+
+/// A list of Telegram Star transactions.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StarTransactions {
+    pub transactions: Vec<StarTransaction>,
+}
+
+/// A Telegram Star transaction.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StarTransaction {
+    /// Unique identifier of the transaction. For payments from users, this is
+    /// the `telegram_payment_charge_id` of the [SuccessfulPayment].
+    pub id: String,
+    /// Number of Telegram Stars transferred.
+    pub amount: i32,
+    /// Number of nanostars transferred, may be 0-999999999.
+    pub nanostar_amount: Option<i32>,
+    /// Date the transaction was created, as a unix timestamp.
+    pub date: i64,
+    /// Source of an incoming transaction.
+    pub source: Option<TransactionPartner>,
+    /// Receiver of an outgoing transaction.
+    pub receiver: Option<TransactionPartner>,
+}
+
+/// The other party in a [StarTransaction].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TransactionPartner {
+    /// A user, such as for a payment or a refund.
+    User {
+        user: User,
+        /// Bot-defined payload from the invoice.
+        invoice_payload: Option<String>,
+    },
+    /// A withdrawal to Fragment.
+    Fragment {
+        withdrawal_state: Option<RevenueWithdrawalState>,
+    },
+    /// A withdrawal for Telegram Ads.
+    TelegramAds,
+    /// Paid broadcasts or other usage of the Telegram API.
+    TelegramApi {
+        /// Number of successful requests that exceeded the limits.
+        request_count: i32,
+    },
+    /// An unknown partner.
+    Other,
+    /// A partner type not supported by this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The state of a withdrawal to Fragment.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RevenueWithdrawalState {
+    Pending,
+    Succeeded {
+        /// Date the withdrawal was completed, as a unix timestamp.
+        date: i64,
+        /// URL to view the transaction.
+        url: String,
+    },
+    Failed,
+    /// A state not supported by this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Telegram Passport data shared with the bot by the user.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PassportData {
+    /// Documents and other information that was shared with the bot.
+    pub data: Vec<EncryptedPassportElement>,
+    /// Credentials required to decrypt the data.
+    pub credentials: EncryptedCredentials,
+}
+
+/// A file uploaded to Telegram Passport. Files are encrypted and must be
+/// decrypted after downloading.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PassportFile {
+    pub file_id: String,
+    pub file_unique_id: String,
+    pub file_size: i64,
+    /// Date the file was uploaded, as a unix timestamp.
+    pub file_date: i64,
+}
+
+/// The type of a Telegram Passport element.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum EncryptedPassportElementType {
+    #[default]
+    PersonalDetails,
+    Passport,
+    DriverLicense,
+    IdentityCard,
+    InternalPassport,
+    Address,
+    UtilityBill,
+    BankStatement,
+    RentalAgreement,
+    PassportRegistration,
+    TemporaryRegistration,
+    PhoneNumber,
+    Email,
+    /// An element type not supported by this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A document or other information shared with the bot by the user.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EncryptedPassportElement {
+    #[serde(rename = "type")]
+    pub element_type: EncryptedPassportElementType,
+    /// Base64-encoded encrypted data for `personal_details`, `passport`,
+    /// `driver_license`, `identity_card`, `internal_passport`, and `address`
+    /// elements.
+    pub data: Option<String>,
+    /// The user's verified phone number, for `phone_number` elements.
+    pub phone_number: Option<String>,
+    /// The user's verified email address, for `email` elements.
+    pub email: Option<String>,
+    /// Encrypted files with documents, for `utility_bill`, `bank_statement`,
+    /// `rental_agreement`, `passport_registration`, and
+    /// `temporary_registration` elements.
+    pub files: Option<Vec<PassportFile>>,
+    /// Encrypted front side of the document.
+    pub front_side: Option<PassportFile>,
+    /// Encrypted reverse side of the document.
+    pub reverse_side: Option<PassportFile>,
+    /// Encrypted selfie of the user holding the document.
+    pub selfie: Option<PassportFile>,
+    /// Encrypted translations of the document.
+    pub translation: Option<Vec<PassportFile>>,
+    /// Base64-encoded hash of the element, used with
+    /// [PassportElementError](crate::requests::PassportElementError).
+    pub hash: String,
+}
+
+/// Encrypted credentials required to decrypt [PassportData].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EncryptedCredentials {
+    /// Base64-encoded encrypted JSON data with the credentials.
+    pub data: String,
+    /// Base64-encoded hash of the data.
+    pub hash: String,
+    /// Base64-encoded secret, encrypted with the bot's public RSA key.
+    pub secret: String,
+}
+
+/// A topic in a forum supergroup.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ForumTopic {
+    /// Identifier of the topic, used as `message_thread_id`.
+    pub message_thread_id: i32,
+    pub name: String,
+    /// Color of the topic icon in RGB format.
+    pub icon_color: i32,
+    /// Custom emoji identifier shown as the topic icon.
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+/// A service message about a new forum topic.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ForumTopicCreated {
+    pub name: String,
+    /// Color of the topic icon in RGB format.
+    pub icon_color: i32,
+    /// Custom emoji identifier shown as the topic icon.
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+/// A service message about an edited forum topic.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ForumTopicEdited {
+    /// New name of the topic, if it was edited.
+    pub name: Option<String>,
+    /// New custom emoji identifier of the topic icon, if it was edited. An
+    /// empty string if the icon was removed.
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+/// A service message about a forum topic being closed. It currently holds no
+/// information.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ForumTopicClosed {}
+
+/// A service message about a forum topic being reopened. It currently holds
+/// no information.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ForumTopicReopened {}
+
+/// A service message about the General forum topic being hidden. It
+/// currently holds no information.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GeneralForumTopicHidden {}
+
+/// A service message about the General forum topic being unhidden. It
+/// currently holds no information.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GeneralForumTopicUnhidden {}
@@ -0,0 +1,153 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use futures_util::stream::{self, Stream};
+use serde::Serialize;
+use tracing::{debug, warn};
+
+use crate::requests::GetUpdates;
+use crate::{Error, Telegram, TelegramErrorKind, TelegramRequest, Update};
+
+/// Delay before retrying the first failed request for updates.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+/// Longest delay between retries of failed requests for updates.
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// A [GetUpdates] request that returns each update as raw JSON, so one update
+/// that cannot be parsed does not prevent the rest of the batch from being
+/// delivered.
+#[derive(Serialize, Debug)]
+struct RawGetUpdates<'a>(&'a GetUpdates);
+
+impl TelegramRequest for RawGetUpdates<'_> {
+    type Response = Vec<serde_json::Value>;
+
+    fn endpoint(&self) -> &str {
+        "getUpdates"
+    }
+}
+
+/// State kept between polls of the update stream.
+struct PollState<'a> {
+    telegram: &'a Telegram,
+    request: GetUpdates,
+    pending: VecDeque<serde_json::Value>,
+    delay: Option<Duration>,
+    wait: Option<Duration>,
+}
+
+impl PollState<'_> {
+    /// Double the current delay, starting at [INITIAL_BACKOFF] and never
+    /// exceeding [MAX_BACKOFF].
+    fn backoff(&mut self) -> Duration {
+        let delay = self
+            .delay
+            .map(|delay| (delay * 2).min(MAX_BACKOFF))
+            .unwrap_or(INITIAL_BACKOFF);
+
+        self.delay = Some(delay);
+        delay
+    }
+}
+
+impl Telegram {
+    /// Create a stream of [Updates](Update) using long polling.
+    ///
+    /// The provided [GetUpdates] is used for every request, so `timeout`
+    /// should be set to enable long polling and `allowed_updates` may be used
+    /// to filter the kinds of updates received. The offset is managed by the
+    /// stream: an update is confirmed with Telegram when the next batch is
+    /// requested, which only happens after every update in the current batch
+    /// has been yielded. Updates yielded right before the stream is dropped
+    /// are not confirmed and will be returned again by a future request unless
+    /// its offset is set past them.
+    ///
+    /// Updates that cannot be parsed are yielded as errors and skipped, the
+    /// rest of the batch is still delivered.
+    ///
+    /// Network errors are logged and retried with exponential backoff without
+    /// being yielded. Other errors are yielded and polling continues after a
+    /// delay, so the stream never ends on its own.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use futures_util::StreamExt;
+    /// # use tgbotapi::{Telegram, requests::GetUpdates};
+    /// # async fn run(telegram: Telegram) -> Result<(), tgbotapi::Error> {
+    /// let updates = telegram.updates(GetUpdates {
+    ///     timeout: Some(30),
+    ///     ..Default::default()
+    /// });
+    /// futures_util::pin_mut!(updates);
+    ///
+    /// while let Some(update) = updates.next().await {
+    ///     println!("{:?}", update?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn updates(&self, request: GetUpdates) -> impl Stream<Item = Result<Update, Error>> + '_ {
+        let state = PollState {
+            telegram: self,
+            request,
+            pending: VecDeque::new(),
+            delay: None,
+            wait: None,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(update) = state.pending.pop_front() {
+                    if let Some(update_id) = update.get("update_id").and_then(|id| id.as_i64()) {
+                        state.request.offset = Some(update_id as i32 + 1);
+                    }
+
+                    let update = serde_json::from_value::<Update>(update).map_err(|err| {
+                        warn!("Unable to parse update: {:?}", err);
+                        Error::from(err)
+                    });
+                    return Some((update, state));
+                }
+
+                if let Some(wait) = state.wait.take() {
+                    tokio::time::sleep(wait).await;
+                }
+
+                match state
+                    .telegram
+                    .make_request(&RawGetUpdates(&state.request))
+                    .await
+                {
+                    Ok(updates) => {
+                        debug!("Got {} updates", updates.len());
+
+                        state.delay = None;
+                        state.pending.extend(updates);
+                    }
+                    Err(Error::Request(err)) if !err.is_decode() => {
+                        let delay = state.backoff();
+                        warn!("Unable to get updates, retrying in {:?}: {:?}", delay, err);
+
+                        state.wait = Some(delay);
+                    }
+                    Err(err) => {
+                        let retry_after = match err.kind() {
+                            Some(TelegramErrorKind::TooManyRequests { retry_after }) => retry_after,
+                            _ => None,
+                        };
+
+                        let delay = match retry_after {
+                            Some(retry_after) => Duration::from_secs(retry_after.max(0) as u64),
+                            None => state.backoff(),
+                        };
+                        warn!("Error getting updates, retrying in {:?}: {:?}", delay, err);
+
+                        state.wait = Some(delay);
+                        return Some((Err(err), state));
+                    }
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,156 @@
+use futures_util::stream::{self, Stream};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+use crate::{Error, Update};
+
+/// Header Telegram uses to send the `secret_token` configured with
+/// [SetWebhook](crate::requests::SetWebhook).
+pub static SECRET_TOKEN_HEADER: &str = "X-Telegram-Bot-Api-Secret-Token";
+
+/// The result of handling a webhook request, which should be returned to
+/// Telegram as the HTTP status.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WebhookStatus {
+    /// The request was accepted. Updates that could not be parsed are still
+    /// accepted so Telegram does not keep resending them.
+    Ok,
+    /// The request did not use the `POST` method.
+    MethodNotAllowed,
+    /// The secret token header was missing or incorrect.
+    Unauthorized,
+    /// The stream of updates has been dropped and nothing is processing them.
+    Unavailable,
+    /// Too many updates are waiting to be processed. Telegram will send the
+    /// update again later.
+    TooManyRequests,
+}
+
+impl WebhookStatus {
+    /// The HTTP status code for this result.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            WebhookStatus::Ok => 200,
+            WebhookStatus::MethodNotAllowed => 405,
+            WebhookStatus::Unauthorized => 401,
+            WebhookStatus::Unavailable => 503,
+            WebhookStatus::TooManyRequests => 429,
+        }
+    }
+}
+
+/// A framework-agnostic receiver for webhook requests from Telegram.
+///
+/// Each request is validated and parsed into an [Update], which is then sent
+/// into a stream of the same form as [Telegram::updates](crate::Telegram::updates).
+/// The handler may be cloned to share it between web server workers.
+///
+/// # Example
+///
+/// ```no_run
+/// # use futures_util::StreamExt;
+/// # use tgbotapi::WebhookHandler;
+/// # async fn run(headers: Vec<(String, String)>, body: Vec<u8>) {
+/// let (handler, updates) = WebhookHandler::new(Some("secret".into()));
+///
+/// // Call this from your web framework's request handler.
+/// let status = handler.handle("POST", headers, &body);
+///
+/// futures_util::pin_mut!(updates);
+/// while let Some(update) = updates.next().await {
+///     println!("{:?}", update);
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WebhookHandler {
+    secret_token: Option<String>,
+    sender: mpsc::Sender<Result<Update, Error>>,
+}
+
+/// Number of updates a [WebhookHandler] holds before rejecting new requests.
+pub const DEFAULT_WEBHOOK_CAPACITY: usize = 100;
+
+impl WebhookHandler {
+    /// Create a new handler and the stream that receives its updates.
+    ///
+    /// If a secret token is provided, it must match the `secret_token` used
+    /// with [SetWebhook](crate::requests::SetWebhook).
+    ///
+    /// Up to [DEFAULT_WEBHOOK_CAPACITY] updates are held until the stream
+    /// consumes them. When it is full, requests are rejected with
+    /// [WebhookStatus::TooManyRequests] so Telegram retries them later.
+    pub fn new(secret_token: Option<String>) -> (Self, impl Stream<Item = Result<Update, Error>>) {
+        Self::with_capacity(secret_token, DEFAULT_WEBHOOK_CAPACITY)
+    }
+
+    /// Create a new handler that holds up to `capacity` updates until the
+    /// stream consumes them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is 0.
+    pub fn with_capacity(
+        secret_token: Option<String>,
+        capacity: usize,
+    ) -> (Self, impl Stream<Item = Result<Update, Error>>) {
+        let (sender, receiver) = mpsc::channel(capacity);
+
+        let updates = stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|update| (update, receiver))
+        });
+
+        (
+            Self {
+                secret_token,
+                sender,
+            },
+            updates,
+        )
+    }
+
+    /// Handle a request sent to the webhook.
+    ///
+    /// Headers may be provided in any form that can be viewed as strings for
+    /// names and bytes for values, header names are compared
+    /// case-insensitively.
+    pub fn handle<I, K, V>(&self, method: &str, headers: I, body: &[u8]) -> WebhookStatus
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        if !method.eq_ignore_ascii_case("POST") {
+            return WebhookStatus::MethodNotAllowed;
+        }
+
+        if let Some(secret_token) = &self.secret_token {
+            let authorized = headers.into_iter().any(|(name, value)| {
+                name.as_ref().eq_ignore_ascii_case(SECRET_TOKEN_HEADER)
+                    && constant_time_eq(value.as_ref(), secret_token.as_bytes())
+            });
+
+            if !authorized {
+                warn!("Webhook request had missing or incorrect secret token");
+                return WebhookStatus::Unauthorized;
+            }
+        }
+
+        let update = serde_json::from_slice::<Update>(body).map_err(Error::from);
+        debug!("Got webhook update: {:?}", update);
+
+        match self.sender.try_send(update) {
+            Ok(_) => WebhookStatus::Ok,
+            Err(mpsc::error::TrySendError::Full(_)) => {
+                warn!("Webhook update queue is full, rejecting update");
+                WebhookStatus::TooManyRequests
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => WebhookStatus::Unavailable,
+        }
+    }
+}
+
+/// Compare two byte slices without returning early on the first difference.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}